@@ -0,0 +1,1588 @@
+//! The numbered demos behind the `rust_memory` binary, with the runner that
+//! selects them; compiled only with the "std" feature (threads, files, stdout)
+
+use super::*;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::mem::ManuallyDrop;
+use std::rc::{Rc, Weak};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+
+/// Demonstrates shared ownership: the buffer lives until the last Rc is gone
+pub fn demo_rc_sharing() -> Result<(), BufferError> {
+    let shared: Rc<DataBuffer<i32>> = Rc::new(DataBuffer::new("Shared", 4));
+    println!("  strong_count after creation: {}", Rc::strong_count(&shared));
+    
+    let owner2 = Rc::clone(&shared);  // Copies the pointer, not the data
+    println!("  strong_count after clone:    {}", Rc::strong_count(&shared));
+    
+    {
+        let owner3 = Rc::clone(&shared);
+        println!("  strong_count in inner scope: {}", Rc::strong_count(&owner3));
+    } // owner3 dropped - count decremented, buffer still alive
+    println!("  strong_count after scope:    {}", Rc::strong_count(&shared));
+    
+    drop(owner2);
+    println!("  strong_count after drop:     {}", Rc::strong_count(&shared));
+    
+    println!("  ℹ Dropping the last owner frees the buffer:");
+    drop(shared);
+    Ok(())
+}
+
+/// Compile-time check that a type can be shared across threads
+fn assert_send_sync<T: Send + Sync>() {}
+
+/// Demonstrates immutable sharing across threads via Arc
+pub fn demo_arc_threads() -> Result<(), BufferError> {
+    // Fails to compile if DataBuffer ever gains a non-thread-safe field
+    assert_send_sync::<DataBuffer<i32>>();
+    
+    let mut buffer = DataBuffer::new("ThreadShared", 6);
+    buffer.fill_with_values(-2);
+    let expected = process_buffer(&buffer);
+    
+    let shared = Arc::new(buffer);
+    let handles: Vec<_> = (0..3)
+        .map(|id| {
+            let local = Arc::clone(&shared);
+            thread::spawn(move || {
+                let count = process_buffer(&local);
+                println!("  Thread {} counted {} positive values", id, count);
+                count
+            })
+        })
+        .collect();
+    
+    let total: i32 = handles
+        .into_iter()
+        .map(|handle| handle.join().expect("worker thread panicked"))
+        .sum();
+    println!("  Aggregate count: {} (expected {})", total, expected * 3);
+    println!("  strong_count after join: {}", Arc::strong_count(&shared));
+    Ok(())
+}
+
+/// Demonstrates shared mutation across threads via Arc<Mutex<_>>
+pub fn demo_mutex_buffer() -> Result<(), BufferError> {
+    const THREADS: i32 = 4;
+    const INCREMENTS: i32 = 250;
+    
+    let shared: Arc<Mutex<DataBuffer<i32>>> = Arc::new(Mutex::new(DataBuffer::new("Locked", 3)));
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let local = Arc::clone(&shared);
+            thread::spawn(move || {
+                for _ in 0..INCREMENTS {
+                    // The lock hands out the single &mut that DEMO 3 enforces at compile time
+                    let mut guard = match local.lock() {
+                        Ok(guard) => guard,
+                        Err(poisoned) => {
+                            println!("  ⚠ Lock poisoned by a panicking thread, recovering");
+                            poisoned.into_inner()
+                        }
+                    };
+                    for item in guard.data.iter_mut() {
+                        *item += 1;
+                    }
+                }
+            })
+        })
+        .collect();
+    
+    for handle in handles {
+        handle.join().expect("worker thread panicked");
+    }
+    
+    let guard = match shared.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    println!("  Final values: {:?} (expected {} each)", guard.data, THREADS * INCREMENTS);
+    Ok(())
+}
+
+/// Demonstrates interior mutability: borrow rules checked at runtime
+pub fn demo_refcell() -> Result<(), BufferError> {
+    let shared: Rc<RefCell<DataBuffer<i32>>> =
+        Rc::new(RefCell::new(DataBuffer::new("Cell", 4)));
+    let alias = Rc::clone(&shared);
+    
+    {
+        let mut first = shared.borrow_mut();
+        println!("  State: mutably borrowed by `shared`");
+        first.fill_with_values(5);
+        
+        // A second borrow_mut() here would panic with "already borrowed";
+        // try_borrow_mut reports the same runtime check without panicking
+        match alias.try_borrow_mut() {
+            Ok(_) => println!("  Unexpected: second mutable borrow granted"),
+            Err(e) => println!("  ✗ Second borrow_mut rejected: {}", e),
+        }
+    } // first released here
+    println!("  State: released");
+    
+    {
+        let mut second = alias.borrow_mut();
+        println!("  State: mutably borrowed by `alias`");
+        modify_buffer(&mut second, 2);
+    }
+    println!("  State: released");
+    
+    let reader1 = shared.borrow();
+    let reader2 = alias.borrow();  // Multiple shared borrows are fine
+    println!("  State: two shared borrows, data = {:?}", reader1.data);
+    println!("  Same buffer through alias: {}", reader2.name);
+    Ok(())
+}
+
+/// Demonstrates ownership crossing thread boundaries through a channel
+pub fn demo_channel_pipeline() -> Result<(), BufferError> {
+    let (sender, receiver) = mpsc::channel::<DataBuffer<i32>>();
+    
+    let producer = thread::spawn(move || {
+        let mut expected = 0;
+        for id in 1..=3 {
+            let mut buffer = DataBuffer::new(format!("Job{}", id), id * 2);
+            buffer.fill_with_values(id as i32);
+            expected += buffer.iter().sum::<i32>();
+            println!("  → Sending '{}'", buffer.name);
+            // send() moves the buffer; the producer can no longer touch it
+            if sender.send(buffer).is_err() {
+                println!("  ✗ Consumer hung up");
+                break;
+            }
+        }
+        expected
+    }); // sender dropped when the producer finishes, closing the channel
+    
+    let consumer = thread::spawn(move || {
+        let mut total = 0;
+        for buffer in receiver {
+            println!("  ← Received '{}'", buffer.name);
+            total += buffer.into_sum();
+        }
+        total
+    });
+    
+    let expected = producer.join().expect("producer thread panicked");
+    let total = consumer.join().expect("consumer thread panicked");
+    println!("  Consumer total: {} (expected {})", total, expected);
+    Ok(())
+}
+
+/// Demonstrates deterministic LIFO destruction order
+pub fn demo_drop_order() -> Result<(), BufferError> {
+    let _a: DataBuffer<i32> = DataBuffer::new("A", 1);
+    let _b: DataBuffer<i32> = DataBuffer::new("B", 1);
+    {
+        let _inner: DataBuffer<i32> = DataBuffer::new("Inner", 1);
+        println!("  ℹ Leaving inner scope - 'Inner' drops before any outer buffer");
+    }
+    let _c: DataBuffer<i32> = DataBuffer::new("C", 1);
+    println!("  ℹ Leaving outer scope - expect C, B, A (reverse declaration order)");
+    Ok(())
+}
+
+/// Demonstrates moving data around with std::mem instead of cloning
+pub fn demo_mem_ops() -> Result<(), BufferError> {
+    let mut left = DataBuffer::from(vec![1, 1, 1]);
+    let mut right = DataBuffer::from(vec![2, 2]);
+    
+    // 1. swap: exchanges the two vectors' (pointer, len, capacity) headers only
+    std::mem::swap(&mut left.data, &mut right.data);
+    println!("  After swap:    left = {:?}, right = {:?}", left.data, right.data);
+    
+    // 2. replace: installs a new vector and hands back the old one
+    let old = std::mem::replace(&mut left.data, vec![9, 9, 9, 9]);
+    println!("  After replace: left = {:?}, returned = {:?}", left.data, old);
+    
+    // 3. take: leaves Vec::default() (empty, no allocation) behind
+    let taken = std::mem::take(&mut right.data);
+    println!("  After take:    right = {:?}, taken = {:?}", right.data, taken);
+    println!("  ℹ No element was cloned - only ownership of allocations moved");
+    Ok(())
+}
+
+/// A child that can reach its parent without keeping it alive
+struct ChildNode {
+    buffer: DataBuffer<i32>,
+    parent: Weak<RefCell<DataBuffer<i32>>>,
+}
+
+/// Demonstrates breaking reference cycles with Weak
+pub fn demo_weak_refs() -> Result<(), BufferError> {
+    let child = {
+        let parent: Rc<RefCell<DataBuffer<i32>>> =
+            Rc::new(RefCell::new(DataBuffer::new("Parent", 2)));
+        let child = ChildNode {
+            buffer: DataBuffer::new("Child", 1),
+            parent: Rc::downgrade(&parent),  // No strong count added
+        };
+        println!(
+            "  strong_count = {}, weak_count = {}",
+            Rc::strong_count(&parent),
+            Rc::weak_count(&parent)
+        );
+        
+        match child.parent.upgrade() {
+            Some(p) => println!("  '{}' reached parent '{}'", child.buffer.name, p.borrow().name),
+            None => println!("  Parent already gone"),
+        }
+        child
+    }; // parent's only strong owner dropped here, so the buffer is freed
+    
+    println!("  Weak::strong_count after scope = {}", child.parent.strong_count());
+    match child.parent.upgrade() {
+        Some(_) => println!("  Unexpected: parent still alive"),
+        None => println!("  upgrade() returned None - no cycle, no leak"),
+    }
+    Ok(())
+}
+
+/// Demonstrates opting out of automatic drop and freeing at a chosen point
+pub fn demo_manually_drop() -> Result<(), BufferError> {
+    let mut held = ManuallyDrop::new(DataBuffer::<i32>::new("Manual", 3));
+    {
+        let _automatic: DataBuffer<i32> = DataBuffer::new("Automatic", 1);
+        println!("  ℹ Leaving scope - 'Automatic' drops itself, 'Manual' would not");
+    }
+    
+    held.fill_with_values(1);  // Still usable through Deref/DerefMut
+    println!("  ℹ Work done - dropping 'Manual' explicitly now:");
+    // SAFETY: `held` is never used again after this call, and it has not been
+    // dropped before, so the buffer is dropped exactly once
+    unsafe {
+        ManuallyDrop::drop(&mut held);
+    }
+    println!("  ℹ End of function - nothing left to drop automatically");
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 1: Ownership Transfer (Move Semantics)
+// ═══════════════════════════════════════════════════
+pub fn demo_1() -> Result<(), BufferError> {
+    let buffer1: DataBuffer<i32> = DataBuffer::new("Buffer1", 5);
+    buffer1.display_info();
+    
+    let buffer1_moved = buffer1;  // Ownership transferred
+    buffer1_moved.display_info();
+    
+    // buffer1.display_info();  // ❌ Compile error: value moved
+    println!("  ℹ buffer1 is no longer accessible\n");
+    Ok(())
+} // buffer1_moved dropped here
+
+// ═══════════════════════════════════════════════════
+// DEMO 2: Borrowing (Immutable)
+// ═══════════════════════════════════════════════════
+pub fn demo_2() -> Result<(), BufferError> {
+    let buffer2: DataBuffer<i32> = DataBuffer::new("Buffer2", 5);
+    
+    // Multiple immutable borrows allowed
+    let count1 = process_buffer(&buffer2);
+    let count2 = process_buffer(&buffer2);
+    
+    println!("  Counts: {}, {}", count1, count2);
+    buffer2.display_info();  // buffer2 still valid
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 3: Mutable Borrowing
+// ═══════════════════════════════════════════════════
+pub fn demo_3() -> Result<(), BufferError> {
+    let mut buffer3: DataBuffer<i32> = DataBuffer::new("Buffer3", 8);
+    buffer3.fill_with_values(10);
+    
+    // Only one mutable borrow at a time
+    modify_buffer(&mut buffer3, 2);
+    
+    // let ref1 = &mut buffer3;
+    // let ref2 = &mut buffer3;  // ❌ Compile error: already borrowed
+    
+    buffer3.display_info();
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 4: Consuming (Taking Ownership)
+// ═══════════════════════════════════════════════════
+pub fn demo_4() -> Result<(), BufferError> {
+    let mut buffer4: DataBuffer<i32> = DataBuffer::new("Buffer4", 6);
+    buffer4.fill_with_values(1);
+    
+    let sum = buffer4.into_sum();  // buffer4 consumed
+    println!("  Final sum: {}", sum);
+    
+    // buffer4.display_info();  // ❌ Compile error: value moved
+    
+    // Each reducer consumes its own buffer
+    let total = DataBuffer::from(vec![3, 1, 4]).into_reduce(0, |acc, x| acc + x as i64);
+    let product = DataBuffer::from(vec![3, 1, 4]).into_reduce(1, |acc, x| acc * x as i64);
+    let max = DataBuffer::from(vec![3, 1, 4]).into_reduce(i64::MIN, |acc, x| acc.max(x as i64));
+    println!("  Reduced sum {}, product {}, max {}", total, product, max);
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 5: Heap Allocation with Box
+// ═══════════════════════════════════════════════════
+pub fn demo_5() -> Result<(), BufferError> {
+    let boxed_value = Box::new(42);
+    println!("  Boxed value: {}", boxed_value);
+    println!("  Address: {:p}", &*boxed_value);
+    
+    let large_data = Box::new([0u8; 1024 * 1024]);
+    println!("  Large data (1MB) allocated on heap");
+    
+    // A growable buffer can be frozen into a boxed slice with no spare capacity
+    let mut growable: DataBuffer<i32> = DataBuffer::with_capacity("Growable", 16);
+    growable.extend_from_slice(&[1, 2, 3]);
+    let frozen: Box<[i32]> = growable.into_boxed_slice();
+    println!("  Boxed slice: {:?} ({} elements)", frozen, frozen.len());
+    
+    explain_layout();
+    
+    // Box automatically freed when out of scope
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 6: Collections and Ownership
+// ═══════════════════════════════════════════════════
+pub fn demo_6() -> Result<(), BufferError> {
+    let mut cache: HashMap<String, Vec<i32>> = HashMap::new();
+    
+    cache.insert(String::from("key1"), vec![1, 2, 3]);
+    cache.insert(String::from("key2"), vec![4, 5, 6]);
+    
+    // Borrow from HashMap
+    if let Some(values) = cache.get("key1") {
+        println!("  Cache values: {:?}", values);
+    }
+    
+    // Ownership transferred out of HashMap
+    if let Some(values) = cache.remove("key2") {
+        println!("  Removed values: {:?}", values);
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 7: Memory Safety Guarantees
+// ═══════════════════════════════════════════════════
+pub fn demo_7() -> Result<(), BufferError> {
+    println!("  ✓ No dangling pointers - impossible at compile time");
+    println!("  ✓ No double-free - prevented by ownership");
+    println!("  ✓ No use-after-free - borrow checker enforces");
+    println!("  ✓ No data races - enforced at compile time");
+    
+    let mut safe_buffer: DataBuffer<i32> = DataBuffer::new("SafeBuffer", 3);
+    
+    // Checked access surfaces an error instead of touching invalid memory
+    if safe_buffer.set(1, 99).is_ok() {
+        println!("  safe_buffer[1] = {:?}", safe_buffer.get(1));
+    }
+    match safe_buffer.get(10) {
+        Ok(value) => println!("  Unexpected value: {}", value),
+        Err(e) => println!("  ✗ Rejected access: {}", e),
+    }
+    
+    // Opting out of the check moves the proof of validity to the caller. Per access
+    // this saves one compare-and-branch over `get` - rarely measurable, and the
+    // compiler already drops it when a loop bound proves the index in range.
+    for i in 0..safe_buffer.len() {
+        // SAFETY: `i < len` by the loop bound, and nothing resizes the buffer inside it
+        unsafe { safe_buffer.set_unchecked(i, i as i32 * 7) };
+    }
+    // SAFETY: the buffer was created with 3 elements, so index 2 is in range
+    let last = unsafe { safe_buffer.get_unchecked(2) };
+    println!("  Unchecked read of index 2: {}", last);
+    
+    // Invalid sizes are reported before anything is allocated
+    if let Err(e) = DataBuffer::<i32>::try_new("EmptyBuffer", 0) {
+        println!("  ✗ Rejected allocation: {}", e);
+    }
+    if let Err(e) = DataBuffer::<i32>::try_new_with_max("HugeBuffer", 1000, 100) {
+        println!("  ✗ Rejected allocation: {}", e);
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 8: Generic Buffers
+// ═══════════════════════════════════════════════════
+pub fn demo_8() -> Result<(), BufferError> {
+    let mut float_buffer: DataBuffer<f64> = DataBuffer::new("FloatBuffer", 4);
+    float_buffer.fill_with_values(0.5);
+    float_buffer.display_info();
+    
+    // Same ownership rules apply regardless of element type
+    let sum = float_buffer.into_sum();
+    println!("  Final sum: {}", sum);
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 9: Iterating Buffers
+// ═══════════════════════════════════════════════════
+pub fn demo_9() -> Result<(), BufferError> {
+    let mut buffer9: DataBuffer<i32> = DataBuffer::new("Buffer9", 4);
+    buffer9.fill_with_values(1);
+    
+    // Immutable borrow - buffer9 still usable afterwards
+    for value in &buffer9 {
+        println!("  Borrowed: {}", value);
+    }
+    
+    // Mutable borrow - elements changed in place
+    for value in &mut buffer9 {
+        *value *= 10;
+    }
+    println!("  ✓ Scaled each element by 10 via &mut iteration");
+    
+    // Owned - buffer9 moved into the loop
+    for value in buffer9 {
+        println!("  Owned: {}", value);
+    }
+    println!("  ℹ Loop finished, buffer9 dropped above");
+    
+    // buffer9.display_info();  // ❌ Compile error: value moved
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 10: Overflow-Safe Sums
+// ═══════════════════════════════════════════════════
+pub fn demo_10() -> Result<(), BufferError> {
+    let mut big_buffer: DataBuffer<i32> = DataBuffer::new("BigBuffer", 3);
+    big_buffer.fill_with_values(i32::MAX - 2);
+    
+    // into_sum() would panic here in debug builds
+    println!("  checked_sum:    {:?}", big_buffer.checked_sum());
+    println!("  saturating_sum: {}", big_buffer.saturating_sum());
+    println!("  wide_sum:       {}", big_buffer.wide_sum());
+    println!("  stats:          {:?}", big_buffer.stats());
+    
+    // Scaling the same values: modify_buffer wraps, apply_gain clamps
+    let mut wrapped = DataBuffer::from_parts("Wrapped", vec![1, i32::MAX - 1, i32::MIN + 5], true);
+    let mut clamped = DataBuffer::from_parts("Clamped", wrapped.data.clone(), true);
+    modify_buffer(&mut wrapped, 2);
+    clamped.apply_gain(2);
+    println!("  wrapped: {:?}", wrapped.data);
+    println!("  clamped: {:?}", clamped.data);
+    
+    big_buffer.clear();
+    println!(
+        "  is_empty: {}, len {} / capacity {}",
+        big_buffer.is_empty(),
+        big_buffer.len(),
+        big_buffer.capacity()
+    );
+    println!("  stats after clear: {:?}", big_buffer.stats());
+    println!("  into_sum after clear: {}", big_buffer.into_sum());
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 11: Deep Copy (Clone)
+// ═══════════════════════════════════════════════════
+pub fn demo_11() -> Result<(), BufferError> {
+    let mut original: DataBuffer<i32> = DataBuffer::new("Original", 3);
+    original.fill_with_values(1);
+    
+    let mut copy = original.clone();  // New allocation, original untouched
+    println!("  Equal contents after clone: {}", original == copy);
+    modify_buffer(&mut copy, 100);
+    println!("  Equal contents after modify: {}", original.approx_eq(&copy));
+    
+    println!("  Original: {:?}", original.data);
+    println!("  Copy:     {:?}", copy.data);
+    original.display_info();
+    copy.display_info();  // Different memory address
+    
+    // A move keeps the same buffer (and generation); each clone bumps it
+    let moved = original;
+    let second_copy = copy.clone();
+    println!(
+        "  Generations: moved {}, copy {}, copy of copy {}",
+        moved.generation, copy.generation, second_copy.generation
+    );
+    Ok(())
+} // Both buffers dropped independently
+
+// ═══════════════════════════════════════════════════
+// DEMO 12: Buffer Manager
+// ═══════════════════════════════════════════════════
+pub fn demo_12() -> Result<(), BufferError> {
+    let mut manager: BufferManager<i32> = BufferManager::new();
+    manager.insert(DataBuffer::new("Alpha", 2));
+    manager.insert(DataBuffer::new("Beta", 3));
+    manager.insert(DataBuffer::new("Gamma", 4));
+    println!("  Total elements managed: {}", manager.total_elements());
+    
+    if let Some(alpha) = manager.get("Alpha") {
+        alpha.display_info();  // Borrowed from the manager
+    }
+    
+    // Ownership leaves the manager, so the buffer can be consumed
+    if let Some(mut beta) = manager.remove("Beta") {
+        beta.fill_with_values(1);
+        let sum = beta.into_sum();
+        println!("  Beta sum: {}", sum);
+    }
+    println!("  Remaining elements: {}", manager.total_elements());
+    Ok(())
+} // manager dropped here - every remaining buffer is dropped with it
+
+// ═══════════════════════════════════════════════════
+// DEMO 13: Memory Footprint
+// ═══════════════════════════════════════════════════
+pub fn demo_13() -> Result<(), BufferError> {
+    let exact: DataBuffer<i32> = DataBuffer::new("Exact", 10);
+    exact.display_info();
+    
+    let mut grown: DataBuffer<i32> = DataBuffer::new("Grown", 10);
+    grown.data.reserve(90);  // Same length, larger capacity
+    grown.display_info();
+    println!("  Length {} vs capacity {}", grown.data.len(), grown.data.capacity());
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 14: Dynamic Growth
+// ═══════════════════════════════════════════════════
+pub fn demo_14() -> Result<(), BufferError> {
+    let mut growing: DataBuffer<i32> = DataBuffer::with_capacity("Growing", 4);
+    
+    for value in 0..10 {
+        let before = growing.capacity();
+        growing.push(value);
+        if growing.capacity() != before {
+            // Amortized growth: capacity roughly doubles on each reallocation
+            println!("  ↻ Reallocated at len {}: capacity {} → {}", growing.len(), before, growing.capacity());
+        }
+    }
+    
+    println!("  Popped: {:?}", growing.pop());
+    println!("  Final len {} / capacity {}", growing.len(), growing.capacity());
+    
+    let extra = [100, 200];
+    growing.extend_from_slice(&extra);  // extra is only borrowed
+    growing.extend(vec![7, 8, 9]);      // the vector is consumed
+    println!("  After extending: {}", growing);
+    
+    // Length changes freely within the existing capacity
+    growing.truncate(3);
+    println!("  Truncated to len {} / capacity {}", growing.len(), growing.capacity());
+    for &(target, fill) in &[(6, -1), (6, -2), (40, 0)] {
+        let outcome = if growing.resize(target, fill) { "reallocated" } else { "no reallocation" };
+        println!(
+            "  resize({}, {}): len {} / capacity {} ({})",
+            target,
+            fill,
+            growing.len(),
+            growing.capacity(),
+            outcome
+        );
+    }
+    println!("  Tail after growing: {:?}", &growing[3..8]);
+    
+    // Truncating alone leaves the allocation at full size
+    growing.truncate(5);
+    growing.shrink_to_fit();
+    println!("  len {} / capacity {}", growing.len(), growing.capacity());
+    
+    // Starting from nothing: no allocation until the first push
+    let mut from_default: DataBuffer<i32> = DataBuffer::default();
+    println!(
+        "  '{}' starts with len {} / capacity {}",
+        from_default.name(),
+        from_default.len(),
+        from_default.capacity()
+    );
+    from_default.push(42);
+    let mut named: DataBuffer<i32> = DataBuffer::new_empty("Incremental");
+    named.extend(1..=3);
+    println!("  {} and {}", from_default, named);
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 19: JSON Round-Trip (serde feature only)
+// ═══════════════════════════════════════════════════
+pub fn demo_19() -> Result<(), BufferError> {
+    #[cfg(feature = "serde")]
+    {
+        let mut saved: DataBuffer<i32> = DataBuffer::new("Persisted", 3);
+        saved.fill_with_values(7);
+        let json = saved.to_json()?;
+        println!("  JSON: {}", json);
+        let restored = DataBuffer::<i32>::from_json(&json)?;
+        println!("  Restored '{}' with {:?}", restored.name, restored.data);
+    }
+    #[cfg(not(feature = "serde"))]
+    {
+        println!("  ℹ Requires the `serde` feature");
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 20: Byte Layout and Endianness
+// ═══════════════════════════════════════════════════
+pub fn demo_20() -> Result<(), BufferError> {
+    let mut bytes_buffer: DataBuffer<i32> = DataBuffer::new("Bytes", 2);
+    bytes_buffer.set(0, 0x0102_0304)?;
+    bytes_buffer.set(1, -1)?;
+    
+    let le = bytes_buffer.to_bytes_le();
+    println!("  Little-endian: {:02x?}", le);
+    println!("  Big-endian:    {:02x?}", bytes_buffer.to_bytes_be());
+    for line in bytes_buffer.hex_dump().lines() {
+        println!("  {}", line);
+    }
+    
+    let decoded = DataBuffer::from_bytes_le(&le)?;
+    println!("  Decoded: {:?}", decoded.data);
+    if let Err(e) = DataBuffer::from_bytes_le(&le[..le.len() - 1]) {
+        println!("  ✗ Truncated input rejected: {}", e);
+    }
+    
+    // Malformed inputs must come back as errors, never panics
+    let huge_prefix = u64::MAX.to_le_bytes();
+    let malformed: [(&str, &[u8]); 3] = [
+        ("empty input", &[]),
+        ("single stray byte", &[0x2a]),
+        ("absurd length prefix", &huge_prefix),
+    ];
+    for &(label, bytes) in &malformed {
+        match DataBuffer::from_bytes_le(bytes) {
+            Ok(decoded) => println!("  ✓ {}: decoded {:?}", label, decoded.data),
+            Err(e) => println!("  ✗ {}: {}", label, e),
+        }
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 21: Display Formatting
+// ═══════════════════════════════════════════════════
+pub fn demo_21() -> Result<(), BufferError> {
+    let mut small: DataBuffer<i32> = DataBuffer::new("Small", 3);
+    small.fill_with_values(1);
+    let mut large: DataBuffer<i32> = DataBuffer::new("Large", 100);
+    large.fill_with_values(0);
+    
+    // format! only borrows - no println side effects from the buffer itself
+    let summary = format!("{} | {}", small, large);
+    println!("  {}", summary);
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 22: Elementwise Arithmetic
+// ═══════════════════════════════════════════════════
+pub fn demo_22() -> Result<(), BufferError> {
+    let mut buffer_a: DataBuffer<i32> = DataBuffer::new("A", 3);
+    buffer_a.fill_with_values(10);
+    let mut buffer_b: DataBuffer<i32> = DataBuffer::new("B", 3);
+    buffer_b.fill_with_values(1);
+    
+    let sum = buffer_a + buffer_b;  // Both operands moved into the result
+    println!("  {}", sum);
+    // buffer_a.display_info();  // ❌ Compile error: value moved
+    
+    let mut scaled = sum * 2;  // Consumes sum, reuses its allocation
+    scaled *= 10;             // Mutates in place
+    println!("  {}", scaled);
+    
+    let short: DataBuffer<i32> = DataBuffer::new("Short", 2);
+    if let Err(e) = scaled.sub_checked(short) {
+        println!("  ✗ Subtraction rejected: {}", e);
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 23: Order Statistics
+// ═══════════════════════════════════════════════════
+pub fn demo_23() -> Result<(), BufferError> {
+    let mut unsorted: DataBuffer<i32> = DataBuffer::new("Unsorted", 6);
+    for (i, &value) in [40, 10, 60, 20, 50, 30].iter().enumerate() {
+        unsorted.set(i, value)?;
+    }
+    
+    println!("  median: {:?}", unsorted.median());
+    println!(
+        "  p0 / p90 / p100: {:?} / {:?} / {:?}",
+        unsorted.percentile(0.0),
+        unsorted.percentile(90.0),
+        unsorted.percentile(100.0)
+    );
+    println!("  p150: {:?}", unsorted.percentile(150.0));
+    println!("  Original order kept: {:?}", unsorted.data);
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 24: Closure-Based Fill
+// ═══════════════════════════════════════════════════
+pub fn demo_24() -> Result<(), BufferError> {
+    let mut squares: DataBuffer<i32> = DataBuffer::new("Squares", 6);
+    squares.fill_with(|i| (i * i) as i32);
+    println!("  {}", squares);
+    
+    // The closure mutably borrows `calls`, `a` and `b` for the duration of the fill
+    let mut calls = 0;
+    let mut fibonacci: DataBuffer<i32> = DataBuffer::new("Fibonacci", 8);
+    let (mut a, mut b) = (0, 1);
+    fibonacci.fill_with(|_| {
+        calls += 1;
+        let next = a;
+        a = b;
+        b += next;
+        next
+    });
+    println!("  {} ({} closure calls)", fibonacci, calls);
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 25: Functional Transforms
+// ═══════════════════════════════════════════════════
+pub fn demo_25() -> Result<(), BufferError> {
+    let mut source: DataBuffer<i32> = DataBuffer::new("Source", 6);
+    source.fill_with_values(-2);
+    
+    // Both calls only borrow `source`; each result owns a new allocation
+    let doubled = source.map(|x| x * 2);
+    let positives = source.filter(|&x| x > 0);
+    
+    println!("  {}", source);
+    println!("  {}", doubled);
+    println!("  {}", positives);
+    println!("  {}", source.prefix_sum());
+    
+    // Each element is passed to the predicate by reference; nothing is copied out
+    println!(
+        "  Evens: {}, all: {}, over 100: {}",
+        source.count_where(|&x| x % 2 == 0),
+        source.count_where(|_| true),
+        source.count_where(|&x| x > 100)
+    );
+    
+    // Same values as `doubled`, computed on demand; only collect() allocates here
+    let lazy: Vec<i32> = source.scaled_iter(2).collect();
+    println!("  Lazy scaled_iter(2) matches eager map: {}", lazy == doubled.data);
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 26: Slice Views (Deref / AsRef)
+// ═══════════════════════════════════════════════════
+pub fn demo_26() -> Result<(), BufferError> {
+    let mut sliced: DataBuffer<i32> = DataBuffer::new("Sliced", 5);
+    sliced.fill_with(|i| (i * i) as i32);
+    
+    // Slice methods reached through Deref
+    let gaps: Vec<i32> = sliced.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    println!("  first = {:?}, last = {:?}", sliced.first(), sliced.last());
+    println!("  Gaps between neighbours: {:?}", gaps);
+    
+    // &DataBuffer coerces to &[i32] wherever a slice is expected
+    let total: i32 = sum_slice(&sliced);
+    println!("  Sum via &[i32]: {}", total);
+    
+    sliced.as_mut()[0] = -1;  // Mutable slice view through AsMut
+    println!("  {}", sliced);
+    
+    // Index / IndexMut: Vec-style syntax that panics out of range (see `get`)
+    sliced[1] += 100;
+    println!("  sliced[1] = {}, sliced[1..3] = {:?}", sliced[1], &sliced[1..3]);
+    // let oops = sliced[10];  // Would panic: index out of bounds
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 27: Conversions (Move vs Copy)
+// ═══════════════════════════════════════════════════
+pub fn demo_27() -> Result<(), BufferError> {
+    let raw = vec![1, 2, 3, 4];
+    let original_ptr = raw.as_ptr();
+    
+    let wrapped = DataBuffer::from(raw);  // raw moved in
+    let back: Vec<i32> = wrapped.into();  // wrapped moved out
+    
+    println!("  Pointer before: {:p}", original_ptr);
+    println!("  Pointer after:  {:p}", back.as_ptr());
+    println!("  Same allocation: {}", original_ptr == back.as_ptr());
+    
+    // Borrowing a slice forces a copy into new storage
+    if let Ok(copied) = DataBuffer::try_from(&back[..]) {
+        println!(
+            "  Copy pointer:   {:p} (distinct: {})",
+            copied.as_ptr(),
+            copied.as_ptr() != back.as_ptr()
+        );
+    }
+    let empty: &[i32] = &[];
+    if let Err(e) = DataBuffer::try_from(empty) {
+        println!("  ✗ Empty slice rejected: {}", e);
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 28: Splitting and Merging
+// ═══════════════════════════════════════════════════
+pub fn demo_28() -> Result<(), BufferError> {
+    let mut whole: DataBuffer<i32> = DataBuffer::new("Whole", 6);
+    whole.fill_with_values(1);
+    
+    let (mut left, right) = whole.split_at(2)?;
+    // whole.display_info();  // ❌ Compile error: value moved
+    println!("  {}", left);
+    println!("  {}", right);
+    
+    left.append(right);  // right moved in and dropped once emptied
+    // right.display_info();  // ❌ Compile error: value moved
+    println!("  Merged: {}", left);
+    
+    let small: DataBuffer<i32> = DataBuffer::new("Small", 2);
+    if let Err(e) = small.split_at(5) {
+        println!("  ✗ Split rejected: {}", e);
+    }
+    
+    let parts = vec![
+        DataBuffer::from(vec![1]),
+        DataBuffer::from(vec![2, 3]),
+        DataBuffer::from(vec![4, 5, 6]),
+    ];
+    let joined = concat(parts);  // The Vec and every buffer in it are consumed
+    println!("  {}", joined);
+    
+    let mixed = DataBuffer::from_parts("Mixed", vec![1, 2, 3, 4], true);
+    let (evens, odds) = mixed.partition(|&x| x % 2 == 0);
+    // mixed.display_info();  // ❌ Compile error: value moved
+    println!("  {} -> {:?}, {} -> {:?}", evens.name, evens.data, odds.name, odds.data);
+    let (all, none) = joined.partition(|_| true);
+    println!("  Match everything: {} / {} element(s)", all.len(), none.len());
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 29: Parallel Fill with Scoped Threads
+// ═══════════════════════════════════════════════════
+pub fn demo_29() -> Result<(), BufferError> {
+    let mut sequential: DataBuffer<i32> = DataBuffer::new("Sequential", 10);
+    sequential.fill_with_values(5);
+    
+    // Includes more threads than elements - extra threads are simply not spawned
+    for &threads in &[1, 3, 16] {
+        let mut parallel: DataBuffer<i32> = DataBuffer::new(format!("Parallel{}", threads), 10);
+        parallel.fill_parallel(5, threads);
+        println!("  Matches sequential fill: {}", parallel == sequential);
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 30: Allocation Tracking (tracking-alloc feature only)
+// ═══════════════════════════════════════════════════
+pub fn demo_30() -> Result<(), BufferError> {
+    #[cfg(feature = "tracking-alloc")]
+    {
+        let before = current_bytes();
+        let total_before = allocated_bytes();
+        let tracked: DataBuffer<i32> = DataBuffer::new("Tracked", 1000);
+        println!(
+            "  Live bytes after allocation: +{} (≥ {} for the elements)",
+            current_bytes() - before,
+            1000 * std::mem::size_of::<i32>()
+        );
+        drop(tracked);
+        println!("  Live bytes after drop:       +{}", current_bytes().saturating_sub(before));
+        println!("  Total bytes allocated:       +{}", allocated_bytes() - total_before);
+    }
+    #[cfg(not(feature = "tracking-alloc"))]
+    {
+        println!("  ℹ Requires the `tracking-alloc` feature");
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 31: Object Pool
+// ═══════════════════════════════════════════════════
+pub fn demo_31() -> Result<(), BufferError> {
+    let mut pool: BufferPool<i32> = BufferPool::new();
+    
+    let first = pool.acquire("Job1", 64)?;
+    let first_ptr = first.as_ptr();
+    pool.release(first);
+    
+    let second = pool.acquire("Job2", 32)?;
+    println!("  Same backing memory: {}", second.as_ptr() == first_ptr);
+    pool.release(second);
+    
+    // Rejected before the pool is touched, so the pooled buffer stays put
+    if let Err(e) = pool.acquire("Job3", 0) {
+        println!("  ✗ Acquire rejected: {}", e);
+    }
+    Ok(())
+} // pool dropped - pooled buffers are freed now
+
+// ═══════════════════════════════════════════════════
+// DEMO 32: In-Place Ordering
+// ═══════════════════════════════════════════════════
+pub fn demo_32() -> Result<(), BufferError> {
+    let mut values = DataBuffer::from(vec![3, 1, 3, 2, 1, 2]);
+    values.sort();   // Each call takes a short-lived &mut borrow
+    values.dedup();
+    println!("  Unique sorted: {}", values);
+    println!("  Search 2: {:?}, search 5: {:?}", values.binary_search(2), values.binary_search(5));
+    values.reverse();
+    println!("  Reversed:      {}", values);
+    
+    let mut ring = DataBuffer::from(vec![1, 2, 3, 4, 5]);
+    ring.rotate_left(2);
+    println!("  Rotated left 2:  {:?}", ring.data);
+    ring.rotate_right(4);
+    println!("  Rotated right 4: {:?}", ring.data);
+    ring.rotate_left(10);  // 2 * len - a no-op
+    println!("  Rotated left 10: {:?}", ring.data);
+    
+    let unsorted = DataBuffer::from(vec![4, 7, 1, 7]);
+    println!(
+        "  Contains 7: {}, contains 9: {}",
+        unsorted.contains(7),
+        unsorted.contains(9)
+    );
+    // 7 appears twice; only the first index is reported
+    println!("  Position of 7: {:?}, of 9: {:?}", unsorted.position(7), unsorted.position(9));
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 33: Bounded Memory with a Ring Buffer
+// ═══════════════════════════════════════════════════
+pub fn demo_33() -> Result<(), BufferError> {
+    let mut ring: CircularBuffer<i32> = CircularBuffer::new(4);
+    for value in 1..=6 {
+        ring.push(value);
+    }
+    let contents: Vec<&i32> = ring.iter().collect();
+    println!("  Pushed 1..=6 into capacity 4, kept {}: {:?}", ring.len(), contents);
+    println!("  Backing capacity still {}", ring.data.capacity());
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 35: Single-Pass Allocation (MaybeUninit)
+// ═══════════════════════════════════════════════════
+pub fn demo_35() -> Result<(), BufferError> {
+    let mut zeroed_first: DataBuffer<i32> = DataBuffer::new("TwoPass", 8);
+    zeroed_first.fill_with_values(100);  // Writes every element a second time
+    
+    let single_pass: DataBuffer<i32> =
+        DataBuffer::new_uninit_then_fill("OnePass", 8, 100);
+    println!("  {}", single_pass);
+    println!("  Matches new + fill_with_values: {}", single_pass == zeroed_first);
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 38: Quiet Mode
+// ═══════════════════════════════════════════════════
+pub fn demo_38() -> Result<(), BufferError> {
+    println!("  (a quiet buffer is created, filled, summed and dropped below)");
+    let mut silent: DataBuffer<i32> = DataBuffer::new_quiet("Silent", 5);
+    silent.fill_with_values(1);
+    let sum = silent.into_sum();
+    println!("  Sum computed silently: {}", sum);
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 39: Builder
+// ═══════════════════════════════════════════════════
+pub fn demo_39() -> Result<(), BufferError> {
+    let built = DataBufferBuilder::new().name("Built").size(4).fill_start(10).build()?;
+    println!("  {}", built);
+    if let Ok(anonymous) = DataBufferBuilder::new().size(2).build() {
+        println!("  {}", anonymous);
+    }
+    if let Err(e) = DataBufferBuilder::new().name("Nothing").build() {
+        println!("  ✗ Build rejected: {}", e);
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 40: Positional and Bulk Edits
+// ═══════════════════════════════════════════════════
+pub fn demo_40() -> Result<(), BufferError> {
+    let mut edits = DataBuffer::from(vec![2, 4]);
+    let results = [edits.insert(0, 1), edits.insert(2, 3), edits.insert(4, 5)];
+    println!("  Inserted at start/middle/end: {} ({:?})", edits, results);
+    
+    println!("  Removed middle: {:?}", edits.remove(2));
+    println!("  Now: {}", edits);
+    if let Err(e) = edits.insert(10, 0) {
+        println!("  ✗ Insert rejected: {}", e);
+    }
+    if let Err(e) = edits.remove(4) {
+        println!("  ✗ Remove rejected: {}", e);
+    }
+    
+    if edits.swap(0, 3).is_ok() {
+        println!("  Swapped ends: {}", edits);
+    }
+    println!("  swap_remove(0): {:?} → {}", edits.swap_remove(0), edits);
+    if let Err(e) = edits.swap(1, 7) {
+        println!("  ✗ Swap rejected: {}", e);
+    }
+    
+    let mut mixed = DataBuffer::from(vec![-3, 7, -1, 8, 0, 9]);
+    mixed.retain(|&x| x > 0);
+    println!("  Retained positives: {}", mixed);
+    println!("  Drained 0..2: {:?}", mixed.drain_range(0..2));
+    if let Err(e) = mixed.drain_range(0..5) {
+        println!("  ✗ Drain rejected: {}", e);
+    }
+    
+    let mut partial: DataBuffer<i32> = DataBuffer::new("Partial", 6);
+    if partial.fill_range(2..5, 7).is_ok() {
+        println!("  Filled 2..5: {}", partial);
+    }
+    if let Err(e) = partial.fill_range(4..9, 1) {
+        println!("  ✗ Fill rejected: {}", e);
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 41: Histogram
+// ═══════════════════════════════════════════════════
+pub fn demo_41() -> Result<(), BufferError> {
+    let scores = DataBuffer::from(vec![1, 2, 2, 3, 5, 6, 8, 9, 9, 10]);
+    if let Ok(counts) = scores.histogram(5) {
+        for (bin, count) in counts.iter().enumerate() {
+            println!("  bin {}: {}", bin, "█".repeat(*count));
+        }
+    }
+    if let Err(e) = scores.histogram(0) {
+        println!("  ✗ Histogram rejected: {}", e);
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 42: Vector Math
+// ═══════════════════════════════════════════════════
+pub fn demo_42() -> Result<(), BufferError> {
+    let u = DataBuffer::from(vec![3, 4]);
+    let v = DataBuffer::from(vec![2, -1]);
+    println!("  u · v = {:?}", u.dot(&v));
+    println!("  |u| = {}", u.norm());
+    println!("  u / |u| = {:?}", u.normalized()?);
+    
+    // Any pairwise rule; u and v stay usable afterwards
+    println!("  max(u, v) = {:?}", u.zip_with(&v, std::cmp::max)?.data);
+    println!("  u * v = {:?}", u.zip_with(&v, |a, b| a * b)?.data);
+    
+    let w = DataBuffer::from(vec![1, 2, 3]);
+    if let Err(e) = u.dot(&w) {
+        println!("  ✗ Dot product rejected: {}", e);
+    }
+    if let Err(e) = u.zip_with(&w, |a, b| a + b) {
+        println!("  ✗ zip_with rejected: {}", e);
+    }
+    let zero = DataBuffer::from(vec![0, 0, 0]);
+    if let Err(e) = zero.normalized() {
+        println!("  ✗ Normalize rejected: {}", e);
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 43: CSV Export and Import
+// ═══════════════════════════════════════════════════
+pub fn demo_43() -> Result<(), BufferError> {
+    let exported = DataBuffer::from(vec![10, -20, 30]);
+    let csv = exported.to_csv();
+    println!("  Exported: {:?}", csv);
+    
+    let imported = DataBuffer::from_csv(&format!("{}  \n\n", csv))?;
+    println!("  Imported matches: {}", imported == exported);
+    if let Err(e) = DataBuffer::from_csv("1\nabc\n3") {
+        println!("  ✗ Import rejected: {}", e);
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 44: File Persistence
+// ═══════════════════════════════════════════════════
+pub fn demo_44() -> Result<(), BufferError> {
+    let path = std::env::temp_dir().join("rust_memory_demo.bin");
+    let original = DataBuffer::from(vec![5, 10, 15]);
+    
+    original.save_to_file(&path)?;
+    let loaded = DataBuffer::load_from_file(&path);
+    let _ = fs::remove_file(&path);  // Clean up before `?` can return early
+    println!("  Reloaded matches original: {}", loaded? == original);
+    
+    if let Err(e) = DataBuffer::load_from_file(Path::new("does/not/exist.bin")) {
+        println!("  ✗ Load rejected: {}", e);
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 47: Buffers as Set Keys
+// ═══════════════════════════════════════════════════
+pub fn demo_47() -> Result<(), BufferError> {
+    let mut unique: HashSet<DataBuffer<i32>> = HashSet::new();
+    let inputs = [
+        ("First", vec![1, 2]),
+        ("Second", vec![1, 2]),
+        ("Third", vec![3]),
+    ];
+    for (name, values) in inputs {
+        let buffer = DataBuffer::from_parts(name, values, true);
+        // insert() takes ownership; a rejected duplicate is dropped immediately
+        let inserted = unique.insert(buffer);
+        println!("  Inserted '{}': {}", name, inserted);
+    }
+    println!("  Unique contents: {}", unique.len());
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 48: Sorting Buffers
+// ═══════════════════════════════════════════════════
+pub fn demo_48() -> Result<(), BufferError> {
+    let mut buffers: Vec<DataBuffer<i32>> = vec![
+        DataBuffer::from_parts("C", vec![2, 0], true),
+        DataBuffer::from_parts("A", vec![1, 5, 9], true),
+        DataBuffer::from_parts("B", vec![1, 5], true),
+    ];
+    buffers.sort();
+    // [1, 5] is a prefix of [1, 5, 9], so the shorter one comes first
+    for buffer in &buffers {
+        println!("  {} -> {:?}", buffer.name, buffer.data);
+    }
+    
+    // Two sorted owners become one sorted buffer without sorting again
+    let odds = DataBuffer::from_parts("Odds", vec![1, 3, 5], true);
+    let evens = DataBuffer::from_parts("Evens", vec![2, 4, 6], true);
+    let merged = odds.merge_sorted(evens);
+    // println!("{:?}", odds.data);  // ❌ Compile error: odds was moved
+    println!("  {} -> {:?}", merged.name, merged.data);
+    let with_empty = merged.merge_sorted(DataBuffer::new_empty("Nothing"));
+    println!("  {} -> {:?}", with_empty.name, with_empty.data);
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 49: Chunked Processing
+// ═══════════════════════════════════════════════════
+pub fn demo_49() -> Result<(), BufferError> {
+    let buffer: DataBuffer<i32> = DataBuffer::from(vec![1, 2, 3, 4, 5, 6, 7]);
+    for block in buffer.chunks(3)? {
+        println!("  Block {:?} sums to {}", block, sum_slice(block));
+    }
+    if let Err(e) = buffer.chunks(0) {
+        println!("  ✗ chunks(0): {}", e);
+    }
+    
+    // The original is dropped once its elements have moved into the chunks
+    let chunks = buffer.into_chunks(4)?;
+    for chunk in &chunks {
+        println!("  {} -> {:?}", chunk.name, chunk.data);
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 50: Moving Average
+// ═══════════════════════════════════════════════════
+pub fn demo_50() -> Result<(), BufferError> {
+    let signal = DataBuffer::from(vec![2, 4, 6, 8, 3]);
+    for &window in &[1, 3, 5, 0, 6] {
+        match signal.moving_average(window) {
+            Ok(smoothed) => println!("  window {}: {:?}", window, smoothed.data),
+            Err(e) => println!("  ✗ window {}: {}", window, e),
+        }
+    }
+    
+    // Discrete differences: the squares' gaps are the odd numbers
+    let squares = DataBuffer::from(vec![1, 4, 9, 16]);
+    let steps = squares.diff();
+    println!("  {} -> {:?}", steps.name(), steps.data);
+    let single = DataBuffer::from(vec![7]);
+    println!("  diff of one element: {:?}", single.diff().data);
+    
+    // A box kernel sums each window; the flipped [1, 0, -1] is a gradient
+    let ramp = DataBuffer::from(vec![1, 2, 3, 4, 5]);
+    for kernel in &[vec![1, 1, 1], vec![1, 0, -1], vec![1; 6], vec![]] {
+        match ramp.convolve(kernel) {
+            Ok(out) => println!("  convolve {:?}: {:?}", kernel, out.data),
+            Err(e) => println!("  ✗ convolve {:?}: {}", kernel, e),
+        }
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 51: Run-Length Encoding
+// ═══════════════════════════════════════════════════
+pub fn demo_51() -> Result<(), BufferError> {
+    let original = DataBuffer::from(vec![5, 5, 5, 2, 2, 9]);
+    let runs = original.rle_encode();
+    println!("  Encoded {:?} as {:?}", original.data, runs);
+    
+    let decoded = DataBuffer::rle_decode(&runs)?;
+    println!("  Round-trip matches: {}", decoded == original);
+    if let Err(e) = DataBuffer::rle_decode(&[(0, usize::MAX)]) {
+        println!("  ✗ Oversized run rejected: {}", e);
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 52: Metadata Tags
+// ═══════════════════════════════════════════════════
+pub fn demo_52() -> Result<(), BufferError> {
+    let mut tagged = DataBuffer::from(vec![1, 2, 3]);
+    let source = String::from("sensor-7");
+    tagged.set_meta("source", source);  // The String moves into the map
+    // println!("{}", source);  // ❌ Compile error: value moved
+    tagged.set_meta("units", "mV");     // A &str is copied into a new String
+    tagged.set_meta("units", "V");      // Same key - the old value is dropped
+    
+    println!("  source = {:?}", tagged.get_meta("source"));
+    println!("  units = {:?}", tagged.get_meta("units"));
+    println!("  missing = {:?}", tagged.get_meta("missing"));
+    
+    let copy = tagged.clone();  // Tags are deep-copied along with the data
+    println!("  Clone keeps tags: {:?}", copy.get_meta("source"));
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 53: Inline Small Buffer
+// ═══════════════════════════════════════════════════
+pub fn demo_53() -> Result<(), BufferError> {
+    let mut small: SmallBuffer<i32, 4> = SmallBuffer::new();
+    println!(
+        "  SmallBuffer<i32, 4> is {} bytes on the stack",
+        std::mem::size_of::<SmallBuffer<i32, 4>>()
+    );
+    for value in 1..=5 {
+        small.push(value * 10);
+        let location = if small.is_inline() { "inline" } else { "heap" };
+        println!("  push({}) -> len {}, {}", value * 10, small.len(), location);
+    }
+    // The fifth push moved every earlier element over to the Vec
+    println!("  Contents after spill: {:?}", small.as_slice());
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 54: Const-Generic Fixed Buffer
+// ═══════════════════════════════════════════════════
+pub fn demo_54() -> Result<(), BufferError> {
+    let mut fixed: FixedBuffer<4> = FixedBuffer::new();
+    fixed.fill_with_values(1);
+    println!("  FixedBuffer<4> {:?} sums to {}", fixed.data, fixed.sum());
+    
+    // The element count is part of the type, so the whole array is inline
+    println!("  size_of FixedBuffer<4>:    {} bytes", std::mem::size_of::<FixedBuffer<4>>());
+    println!("  size_of FixedBuffer<1024>: {} bytes", std::mem::size_of::<FixedBuffer<1024>>());
+    println!(
+        "  size_of DataBuffer:        {} bytes (elements live on the heap)",
+        std::mem::size_of::<DataBuffer>()
+    );
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 55: Fill Strategy Timing
+// ═══════════════════════════════════════════════════
+pub fn demo_55() -> Result<(), BufferError> {
+    // A rough stand-in for a benchmark harness: one timed run per path.
+    // Compile with `rustc -O` for meaningful numbers.
+    for &size in &[1_000, 1_000_000] {
+        let start = Instant::now();
+        let mut zeroed: DataBuffer<i32> = DataBuffer::new_quiet("Zeroed", size);
+        zeroed.fill_with_values(0);  // Second write over the zero-fill
+        let zeroed_time = start.elapsed();
+        
+        let start = Instant::now();
+        let mut reserved: DataBuffer<i32> =
+            DataBuffer::from_parts("Reserved", Vec::with_capacity(size), false);
+        reserved.extend((0..size).map(|i| i as i32));  // Pushes into reserved space
+        let reserved_time = start.elapsed();
+        
+        let start = Instant::now();
+        let single: DataBuffer<i32> = DataBuffer::new_uninit_then_fill("Single", size, 0);
+        let single_time = start.elapsed();
+        
+        // Timings only mean something if every path built the same buffer
+        let same = zeroed == reserved && reserved == single;
+        println!(
+            "  {:>9} elements: new + fill {:?}, with_capacity + extend {:?}, uninit {:?} (equal: {})",
+            size, zeroed_time, reserved_time, single_time, same
+        );
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 56: Generic Numeric Buffer (num-traits feature only)
+// ═══════════════════════════════════════════════════
+pub fn demo_56() -> Result<(), BufferError> {
+    #[cfg(feature = "num-traits")]
+    {
+        let mut wide: NumBuffer<i64> = NumBuffer::new(4);
+        wide.fill_with_values(3_000_000_000);  // Beyond i32::MAX
+        println!("  NumBuffer<i64> sum: {}", wide.sum());
+        
+        let mut floats: NumBuffer<f64> = NumBuffer::new(2);
+        floats.fill_with_values(3.0);
+        println!(
+            "  NumBuffer<f64> {:?}: dot {}, norm {}",
+            floats.data,
+            floats.dot(&floats)?,
+            floats.norm()
+        );
+    }
+    #[cfg(not(feature = "num-traits"))]
+    {
+        println!("  ℹ Requires the `num-traits` feature");
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 57: NaN-Aware Float Statistics
+// ═══════════════════════════════════════════════════
+pub fn demo_57() -> Result<(), BufferError> {
+    let mut readings = DataBuffer::from(vec![2.5, f64::NAN, -1.0, 4.0, f64::NAN]);
+    
+    // The NaNs are reported separately and leave min/max/mean untouched
+    if let Some(stats) = readings.stats_f64() {
+        println!(
+            "  min {}, max {}, mean {}, {} value(s), {} NaN",
+            stats.min, stats.max, stats.mean, stats.count, stats.nan_count
+        );
+    }
+    readings.sort_f64();
+    println!("  Sorted: {:?}", readings.data);
+    
+    let all_nan = DataBuffer::from(vec![f64::NAN]);
+    println!("  Stats of only NaN: {:?}", all_nan.stats_f64());
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 58: Collecting from Iterators
+// ═══════════════════════════════════════════════════
+pub fn demo_58() -> Result<(), BufferError> {
+    let counted: DataBuffer<i32> = (0..10).collect();
+    println!("  {} -> {:?}", counted.name(), counted.data);
+    
+    // Any iterator works, including adapters chained in front of collect
+    let squares: DataBuffer<i32> = (1..=5).map(|x| x * x).collect();
+    println!("  {} -> {:?}", squares.name(), squares.data);
+    
+    let nothing: DataBuffer<i32> = std::iter::empty().collect();
+    println!("  {} is empty: {}", nothing.name(), nothing.is_empty());
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 59: Double-Ended Buffer
+// ═══════════════════════════════════════════════════
+pub fn demo_59() -> Result<(), BufferError> {
+    let mut deque: DequeBuffer<i32> = DequeBuffer::new();
+    deque.push_back(2);
+    deque.push_back(3);
+    deque.push_front(1);  // No shifting, unlike Vec::insert(0, ..)
+    deque.push_front(0);
+    let order: Vec<&i32> = deque.iter().collect();
+    println!("  Front to back: {:?}", order);
+    println!("  pop_front {:?}, pop_back {:?}", deque.pop_front(), deque.pop_back());
+    println!("  Remaining sum: {}", deque.into_sum());
+    
+    // The DataBuffer's vector is handed over, not copied
+    let buffer = DataBuffer::from(vec![10, 20, 30]);
+    let mut converted = DequeBuffer::from(buffer);
+    converted.push_front(5);
+    let order: Vec<&i32> = converted.iter().collect();
+    println!("  Converted with 5 pushed in front: {:?}", order);
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 60: Borrowed Static Names
+// ═══════════════════════════════════════════════════
+pub fn demo_60() -> Result<(), BufferError> {
+    // A literal is borrowed for the program's lifetime; a computed name is owned
+    let literal: DataBuffer<i32> = DataBuffer::new_empty("Literal");
+    let computed: DataBuffer<i32> = DataBuffer::new_empty(format!("Computed_{}", 7));
+    for buffer in &[&literal, &computed] {
+        let kind = match buffer.name {
+            Cow::Borrowed(_) => "borrowed (no allocation)",
+            Cow::Owned(_) => "owned (heap String)",
+        };
+        println!("  '{}': {}, {} heap bytes", buffer.name(), kind, buffer.heap_bytes());
+    }
+    
+    #[cfg(feature = "tracking-alloc")]
+    {
+        let before = current_bytes();
+        let _named: DataBuffer<i32> = DataBuffer::new_quiet("Quiet", 1);
+        println!("  Live bytes for a 1-element literal-named buffer: +{}", current_bytes() - before);
+    }
+    #[cfg(not(feature = "tracking-alloc"))]
+    {
+        println!("  ℹ Counting live bytes requires the `tracking-alloc` feature");
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 61: Peak Detection
+// ═══════════════════════════════════════════════════
+pub fn demo_61() -> Result<(), BufferError> {
+    // Monotonic data and plateaus like [4, 4] have no strict maximum
+    for values in &[vec![1, 3, 2, 5, 4], vec![1, 2, 3, 4], vec![1, 4, 4, 1], vec![9]] {
+        let signal = DataBuffer::from_parts("Signal", values.clone(), false);
+        println!("  {:?}: peaks at {:?}", signal.data, signal.find_peaks());
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════
+// DEMO 62: Sliding-Window Maximum
+// ═══════════════════════════════════════════════════
+pub fn demo_62() -> Result<(), BufferError> {
+    let readings = DataBuffer::from(vec![1, 3, -1, -3, 5, 3, 6, 7]);
+    for &k in &[3, 1, 0, 9] {
+        match readings.window_max(k) {
+            Ok(maxima) => println!("  k = {}: {:?}", k, maxima),
+            Err(e) => println!("  ✗ k = {}: {}", k, e),
+        }
+    }
+    Ok(())
+}
+
+/// Which demos `main` runs, parsed from the first command-line argument
+#[derive(Debug, PartialEq)]
+pub enum DemoSelection {
+    All,
+    /// 1-based demo number
+    One(usize),
+    Unknown(String),
+}
+
+/// Accepts nothing or "all" (every demo), or a number like "3" or "demo3"
+pub fn select_demo(arg: Option<&str>) -> DemoSelection {
+    let arg = match arg {
+        None | Some("all") => return DemoSelection::All,
+        Some(arg) => arg,
+    };
+    let digits = arg.strip_prefix("demo").unwrap_or(arg);
+    match digits.parse::<usize>() {
+        Ok(n) if n >= 1 && n <= DEMOS.len() => DemoSelection::One(n),
+        _ => DemoSelection::Unknown(arg.to_string()),
+    }
+}
+
+/// A demo reports failures with `?` instead of unwrapping
+pub type DemoFn = fn() -> Result<(), BufferError>;
+
+/// Every demo in order as (title, entry point); DEMO N is `DEMOS[N - 1]`
+/// Demos that began as named helpers keep their names (`demo_rc_sharing`, ...)
+pub const DEMOS: [(&str, DemoFn); 62] = [
+    ("Ownership Transfer", demo_1),
+    ("Immutable Borrowing", demo_2),
+    ("Mutable Borrowing", demo_3),
+    ("Consuming Value", demo_4),
+    ("Heap Allocation", demo_5),
+    ("Collections", demo_6),
+    ("Memory Safety", demo_7),
+    ("Generic Buffers", demo_8),
+    ("Iterating Buffers", demo_9),
+    ("Overflow-Safe Sums", demo_10),
+    ("Deep Copy", demo_11),
+    ("Buffer Manager", demo_12),
+    ("Memory Footprint", demo_13),
+    ("Dynamic Growth", demo_14),
+    ("Shared Ownership (Rc)", demo_rc_sharing),
+    ("Cross-Thread Sharing (Arc)", demo_arc_threads),
+    ("Shared Mutation (Mutex)", demo_mutex_buffer),
+    ("Interior Mutability (RefCell)", demo_refcell),
+    ("JSON Round-Trip", demo_19),
+    ("Byte Layout", demo_20),
+    ("Display Formatting", demo_21),
+    ("Elementwise Arithmetic", demo_22),
+    ("Order Statistics", demo_23),
+    ("Closure-Based Fill", demo_24),
+    ("Functional Transforms", demo_25),
+    ("Slice Views", demo_26),
+    ("Conversions", demo_27),
+    ("Splitting and Merging", demo_28),
+    ("Parallel Fill", demo_29),
+    ("Allocation Tracking", demo_30),
+    ("Object Pool", demo_31),
+    ("In-Place Ordering", demo_32),
+    ("Ring Buffer", demo_33),
+    ("Channel Pipeline", demo_channel_pipeline),
+    ("Single-Pass Allocation", demo_35),
+    ("Drop Order", demo_drop_order),
+    ("swap / replace / take", demo_mem_ops),
+    ("Quiet Mode", demo_38),
+    ("Builder", demo_39),
+    ("Positional and Bulk Edits", demo_40),
+    ("Histogram", demo_41),
+    ("Vector Math", demo_42),
+    ("CSV", demo_43),
+    ("File Persistence", demo_44),
+    ("Weak References", demo_weak_refs),
+    ("ManuallyDrop", demo_manually_drop),
+    ("Buffers as Set Keys", demo_47),
+    ("Sorting Buffers", demo_48),
+    ("Chunked Processing", demo_49),
+    ("Moving Average", demo_50),
+    ("Run-Length Encoding", demo_51),
+    ("Metadata Tags", demo_52),
+    ("Inline Small Buffer", demo_53),
+    ("Fixed-Size Buffer", demo_54),
+    ("Fill Strategy Timing", demo_55),
+    ("Generic Numeric Buffer", demo_56),
+    ("NaN-Aware Float Statistics", demo_57),
+    ("Collecting from Iterators", demo_58),
+    ("Double-Ended Buffer", demo_59),
+    ("Borrowed Static Names", demo_60),
+    ("Peak Detection", demo_61),
+    ("Sliding-Window Maximum", demo_62),
+];
+
+/// Prints DEMO `number`'s header and runs it; numbers outside 1..=DEMOS.len()
+/// are rejected rather than indexing past the table
+pub fn run_demo(number: usize) -> Result<(), BufferError> {
+    let (title, demo) = number
+        .checked_sub(1)
+        .and_then(|index| DEMOS.get(index))
+        .ok_or(BufferError::InvalidArgument("no demo with that number"))?;
+    println!("\n--- DEMO {}: {} ---", number, title);
+    demo()
+}
@@ -0,0 +1,3912 @@
+//! Buffer types and the numbered demos behind the `rust_memory` binary
+//! Build first with `rustc --crate-type=lib --cfg 'feature="std"' memory_buffers.rs`
+//!
+//! "std" is the default feature: it adds the demos, stdout messages, file I/O,
+//! threads and creation timestamps. Leaving it out builds the buffer core as
+//! `no_std` on top of `alloc` for targets without an operating system.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+extern crate core;
+extern crate alloc;
+
+// Optional JSON persistence: build with `--cfg 'feature="serde"'` and
+// `--extern` paths for serde (with "derive") and serde_json
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "serde")]
+extern crate serde_json;
+
+// Optional structured logging: build with `--cfg 'feature="log"'` and an
+// `--extern` path for log; lifecycle messages then go to the installed logger
+#[cfg(feature = "log")]
+extern crate log;
+
+// Optional generic numerics: build with `--cfg 'feature="num-traits"'` and an
+// `--extern` path for num_traits to get `NumBuffer`
+#[cfg(feature = "num-traits")]
+extern crate num_traits;
+
+#[cfg(all(feature = "serde", not(feature = "std")))]
+compile_error!("the \"serde\" feature needs the \"std\" feature");
+#[cfg(all(feature = "tracking-alloc", not(feature = "std")))]
+compile_error!("the \"tracking-alloc\" feature needs the \"std\" feature");
+
+#[cfg(feature = "serde")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "num-traits")]
+use num_traits::{Float, Num};
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, format, string::String, string::ToString, vec, vec::Vec};
+use alloc::borrow::Cow;
+use alloc::collections::VecDeque;
+use core::convert::TryFrom;
+use core::fmt::{self, Display};
+use core::hash::{Hash, Hasher};
+use core::iter::{FromIterator, FusedIterator, Sum};
+use core::ops::{Add, Deref, Index, IndexMut, Mul, MulAssign, Range, Sub};
+use core::slice::SliceIndex;
+#[cfg(feature = "std")]
+use std::error::Error;
+#[cfg(feature = "std")]
+use std::fs;
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "std")]
+use std::path::Path;
+#[cfg(feature = "std")]
+use std::thread;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+// Keyed storage for tags and the manager: HashMap needs std's random hasher,
+// so the alloc-only build falls back to an ordered BTreeMap
+#[cfg(feature = "std")]
+use std::collections::HashMap as Map;
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap as Map;
+
+#[cfg(feature = "std")]
+mod demos;
+#[cfg(feature = "std")]
+pub use demos::*;
+
+// Optional byte counting: build with `--cfg 'feature="tracking-alloc"'`
+#[cfg(feature = "tracking-alloc")]
+use std::alloc::{GlobalAlloc, Layout, System};
+#[cfg(feature = "tracking-alloc")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "tracking-alloc")]
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+/// Allocated minus freed, kept as one counter so a reader never sees a free
+/// without its matching allocation (two separate loads could underflow)
+#[cfg(feature = "tracking-alloc")]
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps the system allocator and counts every byte handed out and returned
+#[cfg(feature = "tracking-alloc")]
+struct TrackingAllocator;
+
+#[cfg(feature = "tracking-alloc")]
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+            LIVE.fetch_add(layout.size(), Ordering::Relaxed);
+        }
+        ptr
+    }
+    
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+    
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // Counted as allocating the new block and freeing the old one
+            ALLOCATED.fetch_add(new_size, Ordering::Relaxed);
+            LIVE.fetch_add(new_size, Ordering::Relaxed);
+            LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "tracking-alloc")]
+#[global_allocator]
+static GLOBAL: TrackingAllocator = TrackingAllocator;
+
+/// Total bytes ever allocated by this process
+#[cfg(feature = "tracking-alloc")]
+pub fn allocated_bytes() -> usize {
+    ALLOCATED.load(Ordering::Relaxed)
+}
+
+/// Bytes currently live (allocated minus freed)
+#[cfg(feature = "tracking-alloc")]
+pub fn current_bytes() -> usize {
+    LIVE.load(Ordering::Relaxed)
+}
+
+/// Errors surfaced by DataBuffer instead of panicking
+#[derive(Debug)]
+pub enum BufferError {
+    /// Index was past the end of the buffer
+    OutOfBounds { index: usize, len: usize },
+    /// Requested a zero-sized buffer
+    EmptyBuffer,
+    /// Requested more elements than the allowed maximum
+    TooLarge { requested: usize, max: usize },
+    /// Byte input ended before the declared number of elements
+    Truncated { expected: usize, actual: usize },
+    /// Elementwise operation on buffers of different lengths
+    LengthMismatch { left: usize, right: usize },
+    /// Range was reversed or extended past the end of the buffer
+    InvalidRange { start: usize, end: usize, len: usize },
+    /// A numeric parameter was outside its valid domain
+    InvalidArgument(&'static str),
+    /// Normalizing a vector whose elements are all zero
+    ZeroNorm,
+    /// Text input contained a token that is not an integer (1-based line number)
+    Parse { line: usize, token: String },
+    /// Reading or writing a file failed
+    #[cfg(feature = "std")]
+    Io(io::Error),
+    /// JSON encoding or decoding failed
+    #[cfg(feature = "serde")]
+    Serialization(String),
+}
+
+impl Display for BufferError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BufferError::OutOfBounds { index, len } => {
+                write!(f, "index {} out of bounds for buffer of length {}", index, len)
+            }
+            BufferError::EmptyBuffer => write!(f, "buffer size must be greater than zero"),
+            BufferError::TooLarge { requested, max } => {
+                write!(f, "requested {} elements exceeds maximum of {}", requested, max)
+            }
+            BufferError::Truncated { expected, actual } => {
+                write!(f, "expected {} bytes but only {} were provided", expected, actual)
+            }
+            BufferError::LengthMismatch { left, right } => {
+                write!(f, "length mismatch: {} vs {} elements", left, right)
+            }
+            BufferError::InvalidRange { start, end, len } => {
+                write!(f, "range {}..{} is invalid for buffer of length {}", start, end, len)
+            }
+            BufferError::InvalidArgument(msg) => write!(f, "invalid argument: {}", msg),
+            BufferError::ZeroNorm => write!(f, "cannot normalize a vector with zero norm"),
+            BufferError::Parse { line, ref token } => {
+                write!(f, "line {}: '{}' is not a valid integer", line, token)
+            }
+            #[cfg(feature = "std")]
+            BufferError::Io(ref e) => write!(f, "I/O error: {}", e),
+            #[cfg(feature = "serde")]
+            BufferError::Serialization(ref msg) => write!(f, "serialization failed: {}", msg),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for BufferError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            BufferError::Io(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for BufferError {
+    fn from(e: io::Error) -> Self {
+        BufferError::Io(e)
+    }
+}
+
+/// Default upper bound on elements accepted by `DataBuffer::try_new`
+pub const MAX_BUFFER_SIZE: usize = 16 * 1024 * 1024;
+
+/// Structure to demonstrate ownership (generic over the element type)
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DataBuffer<T = i32> {
+    data: Vec<T>,
+    name: Cow<'static, str>,
+    /// When false, every lifecycle message (including drop) is suppressed
+    #[cfg_attr(feature = "serde", serde(skip, default = "default_verbose"))]
+    verbose: bool,
+    /// Set when the buffer is assembled; a move keeps it, a clone gets a fresh one
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "serde", serde(skip, default = "Instant::now"))]
+    created_at: Instant,
+    /// 0 for an original buffer, one more than the source for each clone
+    #[cfg_attr(feature = "serde", serde(skip))]
+    generation: u64,
+    /// Free-form string tags; ignored by equality and hashing like `name`
+    #[cfg_attr(feature = "serde", serde(default))]
+    metadata: Map<String, String>,
+}
+
+#[cfg(feature = "serde")]
+fn default_verbose() -> bool {
+    true
+}
+
+/// Shared size validation for every constructor that allocates `size` elements
+fn check_size(size: usize, max: usize) -> Result<(), BufferError> {
+    if size == 0 {
+        return Err(BufferError::EmptyBuffer);
+    }
+    if size > max {
+        return Err(BufferError::TooLarge { requested: size, max });
+    }
+    Ok(())
+}
+
+impl<T> DataBuffer<T> {
+    /// Assembles a buffer from an existing vector without printing anything
+    fn from_parts(name: impl Into<Cow<'static, str>>, data: Vec<T>, verbose: bool) -> Self {
+        DataBuffer {
+            data,
+            name: name.into(),
+            verbose,
+            #[cfg(feature = "std")]
+            created_at: Instant::now(),
+            generation: 0,
+            metadata: Map::new(),
+        }
+    }
+    
+    /// Emits a lifecycle message unless the buffer is in quiet mode: printed to
+    /// stdout by default, or sent to `log` at info level with the "log" feature
+    /// An alloc-only build without "log" has nowhere to send it and drops it
+    fn log_event(&self, msg: &str) {
+        if self.verbose {
+            #[cfg(feature = "log")]
+            log::info!("{}", msg.trim_start());
+            #[cfg(all(feature = "std", not(feature = "log")))]
+            println!("{}", msg);
+            #[cfg(not(any(feature = "std", feature = "log")))]
+            let _ = msg;
+        }
+    }
+    
+    /// Creates a new DataBuffer (takes ownership of name)
+    /// Panics on an invalid size - use `try_new` to handle the error instead
+    pub fn new(name: impl Into<Cow<'static, str>>, size: usize) -> Self
+    where
+        T: Clone + Default,
+    {
+        DataBuffer::try_new(name, size).unwrap()
+    }
+    
+    /// Fallible constructor - rejects empty or oversized buffers
+    pub fn try_new(name: impl Into<Cow<'static, str>>, size: usize) -> Result<Self, BufferError>
+    where
+        T: Clone + Default,
+    {
+        DataBuffer::try_new_with_max(name, size, MAX_BUFFER_SIZE)
+    }
+    
+    /// Fallible constructor with a caller-supplied size limit
+    pub fn try_new_with_max(name: impl Into<Cow<'static, str>>, size: usize, max: usize) -> Result<Self, BufferError>
+    where
+        T: Clone + Default,
+    {
+        DataBuffer::build(name, size, max, true)
+    }
+    
+    /// Same as `new`, but the buffer never prints - not even when dropped
+    pub fn new_quiet(name: impl Into<Cow<'static, str>>, size: usize) -> Self
+    where
+        T: Clone + Default,
+    {
+        DataBuffer::build(name, size, MAX_BUFFER_SIZE, false).unwrap()
+    }
+    
+    /// Shared by the sized constructors: validates `size` against `max` and
+    /// allocates it zeroed, printing only when `verbose`
+    fn build(
+        name: impl Into<Cow<'static, str>>,
+        size: usize,
+        max: usize,
+        verbose: bool,
+    ) -> Result<Self, BufferError>
+    where
+        T: Clone + Default,
+    {
+        check_size(size, max)?;
+        let buffer = DataBuffer::from_parts(name, vec![T::default(); size], verbose);
+        buffer.log_event(&format!("✓ Creating buffer '{}' with {} elements", buffer.name, size));
+        buffer.log_event("  Memory allocated for vector");
+        Ok(buffer)
+    }
+    
+    /// Allocates and fills in one pass - each element is written exactly once,
+    /// avoiding the zero-fill that `new` + `fill_with_values` performs first
+    pub fn new_uninit_then_fill(name: impl Into<Cow<'static, str>>, size: usize, start: T) -> Self
+    where
+        T: Add<Output = T> + From<i32> + Copy,
+    {
+        let mut data: Vec<T> = Vec::with_capacity(size);
+        
+        // The spare capacity is exposed as `MaybeUninit<T>`, so we can write to it
+        // without ever reading (or dropping) the uninitialized memory underneath
+        for (i, slot) in data.spare_capacity_mut()[..size].iter_mut().enumerate() {
+            slot.write(start + T::from(i as i32));
+        }
+        // SAFETY: capacity is at least `size` (with_capacity above), and the loop
+        // initialized every slot in `0..size`, so all elements up to the new length
+        // are valid `T` values. If an addition panics mid-loop the length is still 0,
+        // so partially written slots are never observed (and `T: Copy` needs no drop).
+        unsafe {
+            data.set_len(size);
+        }
+        let buffer = DataBuffer::from_parts(name, data, true);
+        buffer.log_event(&format!(
+            "✓ Creating buffer '{}' with {} elements (single write pass)",
+            buffer.name, size
+        ));
+        buffer
+    }
+    
+    /// Reserves room for `cap` elements without initializing any (length 0)
+    pub fn with_capacity(name: impl Into<Cow<'static, str>>, cap: usize) -> Self {
+        let buffer = DataBuffer::from_parts(name, Vec::with_capacity(cap), true);
+        buffer.log_event(&format!("✓ Creating empty buffer '{}' with capacity {}", buffer.name, cap));
+        buffer
+    }
+    
+    /// Named buffer with no elements and no allocation yet - fill it with
+    /// `push` or `extend`
+    pub fn new_empty(name: impl Into<Cow<'static, str>>) -> Self {
+        let buffer = DataBuffer::from_parts(name, Vec::new(), true);
+        buffer.log_event(&format!("✓ Creating empty buffer '{}' (nothing allocated)", buffer.name));
+        buffer
+    }
+    
+    /// Number of elements the buffer can hold before reallocating
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+    
+    /// Borrows the buffer's name (fields are private outside this crate)
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    
+    /// Number of initialized elements
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+    
+    /// True when there are no initialized elements (capacity may still be reserved)
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+    
+    /// Drops every element but keeps the allocation for reuse
+    pub fn clear(&mut self) {
+        self.data.clear();
+        self.log_event(&format!(
+            "  ♻ Cleared buffer '{}' (capacity {} retained)",
+            self.name,
+            self.data.capacity()
+        ));
+    }
+    
+    /// Releases spare capacity back to the allocator
+    pub fn shrink_to_fit(&mut self) {
+        let before = self.data.capacity();
+        self.data.shrink_to_fit();
+        let reclaimed = (before - self.data.capacity()) * core::mem::size_of::<T>();
+        self.log_event(&format!(
+            "  ♻ Shrunk buffer '{}': capacity {} → {} ({} bytes reclaimed)",
+            self.name,
+            before,
+            self.data.capacity(),
+            reclaimed
+        ));
+    }
+    
+    /// Appends an element, reallocating if capacity is exhausted
+    pub fn push(&mut self, value: T) {
+        self.data.push(value);
+    }
+    
+    /// Removes the last element, handing ownership to the caller
+    pub fn pop(&mut self) -> Option<T> {
+        self.data.pop()
+    }
+    
+    /// Inserts at `index`, shifting later elements right (`index == len` appends)
+    pub fn insert(&mut self, index: usize, value: T) -> Result<(), BufferError> {
+        if index > self.data.len() {
+            return Err(BufferError::OutOfBounds {
+                index,
+                len: self.data.len(),
+            });
+        }
+        self.data.insert(index, value);
+        Ok(())
+    }
+    
+    /// Removes and returns the element at `index`, shifting later elements left
+    pub fn remove(&mut self, index: usize) -> Result<T, BufferError> {
+        if index >= self.data.len() {
+            return Err(BufferError::OutOfBounds {
+                index,
+                len: self.data.len(),
+            });
+        }
+        Ok(self.data.remove(index))
+    }
+    
+    /// Exchanges two elements after validating both indices
+    pub fn swap(&mut self, i: usize, j: usize) -> Result<(), BufferError> {
+        let len = self.data.len();
+        for &index in &[i, j] {
+            if index >= len {
+                return Err(BufferError::OutOfBounds { index, len });
+            }
+        }
+        self.data.swap(i, j);
+        Ok(())
+    }
+    
+    /// O(1) removal: the last element fills the hole, so order is not preserved
+    pub fn swap_remove(&mut self, index: usize) -> Result<T, BufferError> {
+        if index >= self.data.len() {
+            return Err(BufferError::OutOfBounds {
+                index,
+                len: self.data.len(),
+            });
+        }
+        Ok(self.data.swap_remove(index))
+    }
+    
+    /// Keeps only the elements matching `pred`, dropping the rest in place
+    pub fn retain(&mut self, pred: impl FnMut(&T) -> bool) {
+        self.data.retain(pred);
+    }
+    
+    /// Removes a contiguous range and hands the removed elements to the caller
+    pub fn drain_range(&mut self, range: Range<usize>) -> Result<Vec<T>, BufferError> {
+        self.check_range(&range)?;
+        Ok(self.data.drain(range).collect())
+    }
+    
+    /// Sets every element in `range` to `value` through a mutable sub-slice
+    pub fn fill_range(&mut self, range: Range<usize>, value: T) -> Result<(), BufferError>
+    where
+        T: Clone,
+    {
+        self.check_range(&range)?;
+        self.data[range].fill(value);
+        Ok(())
+    }
+    
+    fn check_range(&self, range: &Range<usize>) -> Result<(), BufferError> {
+        if range.start > range.end || range.end > self.data.len() {
+            return Err(BufferError::InvalidRange {
+                start: range.start,
+                end: range.end,
+                len: self.data.len(),
+            });
+        }
+        Ok(())
+    }
+    
+    /// Copies every value from a borrowed slice onto the end of the buffer
+    pub fn extend_from_slice(&mut self, values: &[T])
+    where
+        T: Clone,
+    {
+        self.data.extend_from_slice(values);
+    }
+    
+    /// Shortens to `new_len`, dropping the tail; capacity is kept (no-op if already shorter)
+    pub fn truncate(&mut self, new_len: usize) {
+        self.data.truncate(new_len);
+    }
+    
+    /// Grows by appending clones of `value`, or truncates if `new_len` is smaller
+    /// Returns true when growing past the capacity moved the data to a new allocation
+    pub fn resize(&mut self, new_len: usize, value: T) -> bool
+    where
+        T: Clone,
+    {
+        let before = self.data.capacity();
+        self.data.resize(new_len, value);
+        self.data.capacity() != before
+    }
+    
+    /// Borrows self immutably - can have multiple
+    pub fn display_info(&self) {
+        self.log_event(&self.info_string());
+    }
+    
+    /// The text printed by `display_info`, without doing any I/O
+    pub fn info_string(&self) -> String {
+        let info = format!(
+            "  Buffer '{}' has {} elements\n  Memory address: {:p}\n  Footprint: {} bytes ({} on heap)",
+            self.name,
+            self.data.len(),
+            self.data.as_ptr(),
+            self.size_in_bytes(),
+            self.heap_bytes()
+        );
+        // Only std has a clock to measure the age with
+        #[cfg(feature = "std")]
+        let info = format!("{}\n  Age: {:?}, generation {}", info, self.created_at.elapsed(), self.generation);
+        #[cfg(not(feature = "std"))]
+        let info = format!("{}\n  Generation {}", info, self.generation);
+        info
+    }
+    
+    /// Heap bytes reserved by the vector and name (capacity, not length)
+    /// A name borrowed from a string literal lives in the binary and adds nothing
+    pub fn heap_bytes(&self) -> usize {
+        let name_bytes = match self.name {
+            Cow::Borrowed(_) => 0,
+            Cow::Owned(ref owned) => owned.capacity(),
+        };
+        self.data.capacity() * core::mem::size_of::<T>() + name_bytes
+    }
+    
+    /// Total footprint: heap allocations plus the struct itself on the stack
+    pub fn size_in_bytes(&self) -> usize {
+        self.heap_bytes() + core::mem::size_of::<Self>()
+    }
+    
+    /// Bounds-checked read - returns an error instead of reading invalid memory
+    pub fn get(&self, index: usize) -> Result<T, BufferError>
+    where
+        T: Copy,
+    {
+        self.data.get(index).copied().ok_or(BufferError::OutOfBounds {
+            index,
+            len: self.data.len(),
+        })
+    }
+    
+    /// Unchecked read - skips the bounds check that `get` performs
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee `index < self.len()`. An out-of-range index is
+    /// undefined behavior: nothing stops the read, so it may return spare-capacity
+    /// garbage, read past the allocation, or let the optimizer assume it never
+    /// happens. There is no error to catch - prefer `get` unless the check is
+    /// shown to matter.
+    pub unsafe fn get_unchecked(&self, index: usize) -> T
+    where
+        T: Copy,
+    {
+        // SAFETY: the caller upholds `index < len` (see above)
+        unsafe { *self.data.get_unchecked(index) }
+    }
+    
+    /// Unchecked write - skips the bounds check that `set` performs
+    ///
+    /// # Safety
+    ///
+    /// Same contract as `get_unchecked`: `index < self.len()`. Writing out of
+    /// range corrupts whatever memory follows the elements, including other
+    /// allocations, and the program may fail far from the faulty call.
+    pub unsafe fn set_unchecked(&mut self, index: usize, value: T) {
+        // SAFETY: the caller upholds `index < len` (see above)
+        unsafe { *self.data.get_unchecked_mut(index) = value }
+    }
+    
+    /// Bounds-checked write - returns an error instead of writing out of range
+    pub fn set(&mut self, index: usize, value: T) -> Result<(), BufferError> {
+        let len = self.data.len();
+        match self.data.get_mut(index) {
+            Some(slot) => {
+                *slot = value;
+                Ok(())
+            }
+            None => Err(BufferError::OutOfBounds { index, len }),
+        }
+    }
+    
+    /// Stores a tag, taking ownership of both strings; an existing key is overwritten
+    pub fn set_meta(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.metadata.insert(key.into(), value.into());
+    }
+    
+    /// Borrows a tag's value without copying it out of the map
+    pub fn get_meta(&self, key: &str) -> Option<&str> {
+        self.metadata.get(key).map(String::as_str)
+    }
+    
+    /// Borrows self mutably - only one at a time
+    pub fn fill_with_values(&mut self, start: T)
+    where
+        T: Add<Output = T> + From<i32> + Copy,
+    {
+        self.fill_with(|i| start + T::from(i as i32));
+    }
+    
+    /// Same result as `fill_with_values`, split across scoped threads
+    /// Each thread gets a disjoint `&mut` chunk, so no locking is needed
+    #[cfg(feature = "std")]
+    pub fn fill_parallel(&mut self, start: T, threads: usize)
+    where
+        T: Add<Output = T> + From<i32> + Copy + Send,
+    {
+        if self.data.is_empty() {
+            return;
+        }
+        let threads = threads.max(1);
+        let chunk_size = self.data.len().div_ceil(threads);
+        
+        thread::scope(|scope| {
+            for (chunk_index, chunk) in self.data.chunks_mut(chunk_size).enumerate() {
+                let offset = chunk_index * chunk_size;
+                scope.spawn(move || {
+                    for (i, item) in chunk.iter_mut().enumerate() {
+                        *item = start + T::from((offset + i) as i32);
+                    }
+                });
+            }
+        }); // All threads joined here, before the mutable borrow ends
+        self.log_event(&format!(
+            "  ✓ Filled buffer '{}' in {} chunk(s) of up to {}",
+            self.name,
+            self.data.len().div_ceil(chunk_size),
+            chunk_size
+        ));
+    }
+    
+    /// Sets each element to `f(index)`; FnMut lets the closure update captured state
+    pub fn fill_with(&mut self, mut f: impl FnMut(usize) -> T) {
+        for (i, item) in self.data.iter_mut().enumerate() {
+            *item = f(i);
+        }
+        self.log_event(&format!("  ✓ Filled buffer '{}'", self.name));
+    }
+    
+    /// Borrows self and returns a freshly allocated buffer of `f(x)` values
+    pub fn map(&self, f: impl Fn(T) -> T) -> DataBuffer<T>
+    where
+        T: Copy,
+    {
+        let name = format!("{}_mapped", self.name);
+        let data: Vec<T> = self.data.iter().map(|&x| f(x)).collect();
+        self.log_event(&format!("  ✓ Allocated new buffer '{}' ({} elements)", name, data.len()));
+        DataBuffer::from_parts(name, data, self.verbose)
+    }
+    
+    /// Borrows self and returns a freshly allocated buffer of matching elements
+    pub fn filter(&self, pred: impl Fn(&T) -> bool) -> DataBuffer<T>
+    where
+        T: Clone,
+    {
+        let name = format!("{}_filtered", self.name);
+        let data: Vec<T> = self.data.iter().filter(|x| pred(x)).cloned().collect();
+        self.log_event(&format!("  ✓ Allocated new buffer '{}' ({} elements)", name, data.len()));
+        DataBuffer::from_parts(name, data, self.verbose)
+    }
+    
+    /// Counts matching elements without allocating
+    pub fn count_where(&self, pred: impl Fn(&T) -> bool) -> usize {
+        self.data.iter().filter(|x| pred(x)).count()
+    }
+    
+    /// Consumes self and moves its elements into two owned halves
+    pub fn split_at(mut self, mid: usize) -> Result<(DataBuffer<T>, DataBuffer<T>), BufferError> {
+        if mid > self.data.len() {
+            return Err(BufferError::OutOfBounds {
+                index: mid,
+                len: self.data.len(),
+            });
+        }
+        let mut left_data = core::mem::take(&mut self.data);
+        let right_data = left_data.split_off(mid);  // Only the right half is reallocated
+        let left = DataBuffer::from_parts(format!("{}_left", self.name), left_data, self.verbose);
+        let right = DataBuffer::from_parts(format!("{}_right", self.name), right_data, self.verbose);
+        self.log_event(&format!(
+            "  ✓ Split '{}' at {} into '{}' and '{}'",
+            self.name, mid, left.name, right.name
+        ));
+        Ok((left, right))
+    }
+    
+    /// Consumes the buffer and sorts its elements into `("{name}_yes", "{name}_no")`
+    /// by the predicate, keeping their relative order; either side may be empty
+    pub fn partition(mut self, pred: impl Fn(&T) -> bool) -> (DataBuffer<T>, DataBuffer<T>) {
+        let data = core::mem::take(&mut self.data);
+        let (yes, no): (Vec<T>, Vec<T>) = data.into_iter().partition(pred);
+        let matching = DataBuffer::from_parts(format!("{}_yes", self.name), yes, self.verbose);
+        let rest = DataBuffer::from_parts(format!("{}_no", self.name), no, self.verbose);
+        self.log_event(&format!(
+            "  ✓ Partitioned '{}' into {} matching and {} not",
+            self.name,
+            matching.data.len(),
+            rest.data.len()
+        ));
+        (matching, rest)
+    }
+    
+    /// Borrowed fixed-size blocks; the last one may be shorter
+    pub fn chunks(&self, size: usize) -> Result<impl Iterator<Item = &[T]> + '_, BufferError> {
+        if size == 0 {
+            return Err(BufferError::InvalidArgument("chunk size must be non-zero"));
+        }
+        Ok(self.data.chunks(size))
+    }
+    
+    /// Consumes self and moves its elements into owned buffers of at most `size`
+    pub fn into_chunks(mut self, size: usize) -> Result<Vec<DataBuffer<T>>, BufferError> {
+        if size == 0 {
+            return Err(BufferError::InvalidArgument("chunk size must be non-zero"));
+        }
+        let mut elements = core::mem::take(&mut self.data).into_iter();
+        let mut chunks = Vec::new();
+        loop {
+            let chunk: Vec<T> = elements.by_ref().take(size).collect();
+            if chunk.is_empty() {
+                break;
+            }
+            let name = format!("{}_chunk{}", self.name, chunks.len());
+            chunks.push(DataBuffer::from_parts(name, chunk, self.verbose));
+        }
+        self.log_event(&format!(
+            "  ✓ Split '{}' into {} chunks of up to {}",
+            self.name,
+            chunks.len(),
+            size
+        ));
+        Ok(chunks)
+    }
+    
+    /// Reverses element order in place
+    pub fn reverse(&mut self) {
+        self.data.reverse();
+    }
+    
+    /// Cyclic shift left; `n` wraps modulo `len`, so over-rotation never panics
+    pub fn rotate_left(&mut self, n: usize) {
+        if !self.data.is_empty() {
+            let len = self.data.len();
+            self.data.rotate_left(n % len);
+        }
+    }
+    
+    /// Cyclic shift right; `n` wraps modulo `len`, so over-rotation never panics
+    pub fn rotate_right(&mut self, n: usize) {
+        if !self.data.is_empty() {
+            let len = self.data.len();
+            self.data.rotate_right(n % len);
+        }
+    }
+    
+    /// Sorts ascending in place (stable)
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.data.sort();
+    }
+    
+    /// Removes consecutive duplicates in place, like `Vec::dedup`
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.data.dedup();
+    }
+    
+    /// Mirrors `slice::binary_search`: `Ok(index)` if found, else `Err(insertion_point)`
+    /// The buffer must already be sorted (see `sort`), otherwise the result is meaningless
+    pub fn binary_search(&self, target: T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.data.binary_search(&target)
+    }
+    
+    /// Consumes two sorted buffers and merges them in one linear pass (no re-sort);
+    /// both inputs are dropped once their elements have moved into "{a}_merged_{b}"
+    pub fn merge_sorted(mut self, mut other: DataBuffer<T>) -> DataBuffer<T>
+    where
+        T: Ord,
+    {
+        let mut data = Vec::with_capacity(self.data.len() + other.data.len());
+        let mut left = core::mem::take(&mut self.data).into_iter().peekable();
+        let mut right = core::mem::take(&mut other.data).into_iter().peekable();
+        loop {
+            let take_left = match (left.peek(), right.peek()) {
+                (Some(a), Some(b)) => a <= b,  // Ties take from self first, so the merge is stable
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+            data.extend(if take_left { left.next() } else { right.next() });
+        }
+        let name = format!("{}_merged_{}", self.name, other.name);
+        self.log_event(&format!("  ✓ Merged {} elements into '{}'", data.len(), name));
+        DataBuffer::from_parts(name, data, self.verbose)
+    }
+    
+    /// Linear scan; works on unsorted data, unlike `binary_search`
+    pub fn contains(&self, value: T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.data.contains(&value)
+    }
+    
+    /// Index of the first element equal to `value`
+    pub fn position(&self, value: T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.data.iter().position(|x| *x == value)
+    }
+    
+    /// Consumes the buffer into a right-sized `Box<[T]>` (length == capacity)
+    pub fn into_boxed_slice(mut self) -> Box<[T]> {
+        let data = core::mem::take(&mut self.data);
+        let spare_bytes = (data.capacity() - data.len()) * core::mem::size_of::<T>();
+        self.log_event(&format!(
+            "  ✓ Boxing '{}': shrink released {} spare bytes",
+            self.name, spare_bytes
+        ));
+        data.into_boxed_slice()
+    }
+    
+    /// Moves every element of `other` onto the end of self; `other` is dropped here
+    pub fn append(&mut self, mut other: DataBuffer<T>) {
+        self.log_event(&format!(
+            "  ✓ Appending {} elements from '{}' into '{}'",
+            other.data.len(),
+            other.name,
+            self.name
+        ));
+        self.data.append(&mut other.data);
+    }
+    
+    /// Takes ownership (consumes the buffer)
+    #[must_use]
+    pub fn into_sum(self) -> T
+    where
+        T: Sum + Copy + Display,
+    {
+        let sum: T = self.data.iter().copied().sum();
+        self.log_event(&format!("  ✓ Buffer '{}' consumed, sum = {}", self.name, sum));
+        sum
+        // self is dropped here, memory is automatically freed
+    }
+}
+
+/// JSON persistence - both `name` and `data` are written
+#[cfg(feature = "serde")]
+impl<T: Serialize + DeserializeOwned> DataBuffer<T> {
+    pub fn to_json(&self) -> Result<String, BufferError> {
+        serde_json::to_string(self).map_err(|e| BufferError::Serialization(e.to_string()))
+    }
+    
+    /// Rebuilds an owned buffer from JSON (no "Creating buffer" message is printed)
+    pub fn from_json(s: &str) -> Result<DataBuffer<T>, BufferError> {
+        serde_json::from_str(s).map_err(|e| BufferError::Serialization(e.to_string()))
+    }
+}
+
+/// Elementwise arithmetic - consumes both operands (move semantics)
+impl<T: Copy> DataBuffer<T> {
+    /// Elementwise `self + other`, named "{a}+{b}"
+    pub fn add_checked(self, other: DataBuffer<T>) -> Result<DataBuffer<T>, BufferError>
+    where
+        T: Add<Output = T>,
+    {
+        self.zip_into(other, "+", |a, b| a + b)
+    }
+    
+    /// Elementwise `self - other`, named "{a}-{b}"
+    pub fn sub_checked(self, other: DataBuffer<T>) -> Result<DataBuffer<T>, BufferError>
+    where
+        T: Sub<Output = T>,
+    {
+        self.zip_into(other, "-", |a, b| a - b)
+    }
+    
+    /// Pairs elements and applies `f` to each pair, named "{a}_zip_{b}"; unlike
+    /// the operators above, both inputs are only borrowed
+    pub fn zip_with(
+        &self,
+        other: &DataBuffer<T>,
+        f: impl Fn(T, T) -> T,
+    ) -> Result<DataBuffer<T>, BufferError> {
+        if self.data.len() != other.data.len() {
+            return Err(BufferError::LengthMismatch {
+                left: self.data.len(),
+                right: other.data.len(),
+            });
+        }
+        let name = format!("{}_zip_{}", self.name, other.name);
+        let data: Vec<T> = self
+            .data
+            .iter()
+            .zip(other.data.iter())
+            .map(|(&a, &b)| f(a, b))
+            .collect();
+        self.log_event(&format!("  ✓ Allocated new buffer '{}' ({} elements)", name, data.len()));
+        Ok(DataBuffer::from_parts(name, data, self.verbose))
+    }
+    
+    /// Reuses self's allocation for the result; both inputs are dropped afterwards
+    fn zip_into(
+        mut self,
+        other: DataBuffer<T>,
+        op: &str,
+        f: impl Fn(T, T) -> T,
+    ) -> Result<DataBuffer<T>, BufferError> {
+        if self.data.len() != other.data.len() {
+            return Err(BufferError::LengthMismatch {
+                left: self.data.len(),
+                right: other.data.len(),
+            });
+        }
+        let mut data = core::mem::take(&mut self.data);
+        for (a, &b) in data.iter_mut().zip(other.data.iter()) {
+            *a = f(*a, b);
+        }
+        let name = format!("{}{}{}", self.name, op, other.name);
+        self.log_event(&format!("  ✓ Combined into buffer '{}'", name));
+        Ok(DataBuffer::from_parts(name, data, self.verbose))
+    }
+}
+
+/// `a + b` panics on a length mismatch - use `add_checked` to handle it
+impl<T: Add<Output = T> + Copy> Add for DataBuffer<T> {
+    type Output = DataBuffer<T>;
+    
+    fn add(self, other: DataBuffer<T>) -> DataBuffer<T> {
+        self.add_checked(other).expect("cannot add buffers")
+    }
+}
+
+/// `a - b` panics on a length mismatch - use `sub_checked` to handle it
+impl<T: Sub<Output = T> + Copy> Sub for DataBuffer<T> {
+    type Output = DataBuffer<T>;
+    
+    fn sub(self, other: DataBuffer<T>) -> DataBuffer<T> {
+        self.sub_checked(other).expect("cannot subtract buffers")
+    }
+}
+
+/// Summary statistics for an i32 buffer
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub min: i32,
+    pub max: i32,
+    pub mean: f64,
+    pub sum: i64,
+}
+
+/// Overflow-aware summation, specific to i32 buffers
+impl DataBuffer<i32> {
+    /// Returns None instead of panicking/wrapping on overflow
+    pub fn checked_sum(&self) -> Option<i32> {
+        self.data.iter().try_fold(0i32, |acc, &x| acc.checked_add(x))
+    }
+    
+    /// Clamps the true total to i32::MIN / i32::MAX
+    pub fn saturating_sum(&self) -> i32 {
+        self.wide_sum().clamp(i32::MIN as i64, i32::MAX as i64) as i32
+    }
+    
+    /// Widens every element to i64 before summing
+    pub fn wide_sum(&self) -> i64 {
+        self.data.iter().map(|&x| x as i64).sum()
+    }
+    
+    /// Consumes the buffer, folding every element into an i64 accumulator.
+    /// `into_sum` stays generic over `T`, so it cannot delegate here; for i32
+    /// buffers `into_reduce(0, |acc, x| acc + x as i64)` is its widened twin
+    #[must_use]
+    pub fn into_reduce(self, init: i64, f: impl Fn(i64, i32) -> i64) -> i64 {
+        let result = self.data.iter().fold(init, |acc, &x| f(acc, x));
+        self.log_event(&format!("  ✓ Buffer '{}' consumed, reduced to {}", self.name, result));
+        result
+    }
+    
+    /// Single-pass summary; None for an empty buffer
+    pub fn stats(&self) -> Option<Stats> {
+        let first = *self.data.first()?;
+        let (min, max, sum) = self.data.iter().fold(
+            (first, first, 0i64),
+            |(min, max, sum), &x| (min.min(x), max.max(x), sum + x as i64),
+        );
+        Some(Stats {
+            min,
+            max,
+            mean: sum as f64 / self.data.len() as f64,
+            sum,
+        })
+    }
+    
+    /// Middle value (average of the two middle values for even lengths)
+    #[cfg(feature = "std")]
+    pub fn median(&self) -> Option<f64> {
+        self.percentile(50.0)
+    }
+    
+    /// Linearly interpolated percentile; None if empty or `p` is outside 0.0–100.0
+    /// Needs "std", which provides `f64::floor` and `ceil` (as `norm` needs `sqrt`)
+    #[cfg(feature = "std")]
+    pub fn percentile(&self, p: f64) -> Option<f64> {
+        if self.data.is_empty() || !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+        // Sort an owned temporary copy so the buffer's own order is untouched
+        let mut sorted = self.data.clone();
+        sorted.sort_unstable();
+        
+        let rank = p / 100.0 * (sorted.len() - 1) as f64;
+        let lower = rank.floor() as usize;
+        let upper = rank.ceil() as usize;
+        let weight = rank - lower as f64;
+        Some(sorted[lower] as f64 * (1.0 - weight) + sorted[upper] as f64 * weight)
+    }
+    
+    /// Sum of elementwise products in i64; both operands are only borrowed
+    pub fn dot(&self, other: &DataBuffer<i32>) -> Result<i64, BufferError> {
+        if self.data.len() != other.data.len() {
+            return Err(BufferError::LengthMismatch {
+                left: self.data.len(),
+                right: other.data.len(),
+            });
+        }
+        Ok(self
+            .data
+            .iter()
+            .zip(other.data.iter())
+            .map(|(&a, &b)| a as i64 * b as i64)
+            .sum())
+    }
+    
+    /// Euclidean length: sqrt of the sum of squares
+    #[cfg(feature = "std")]
+    pub fn norm(&self) -> f64 {
+        self.data
+            .iter()
+            .map(|&x| (x as f64) * (x as f64))
+            .sum::<f64>()
+            .sqrt()
+    }
+    
+    /// Each element divided by `norm`, giving a unit-length copy
+    #[cfg(feature = "std")]
+    pub fn normalized(&self) -> Result<Vec<f64>, BufferError> {
+        let norm = self.norm();
+        if norm == 0.0 {
+            return Err(BufferError::ZeroNorm);
+        }
+        Ok(self.data.iter().map(|&x| x as f64 / norm).collect())
+    }
+    
+    /// Running totals as a new i64 buffer, so large inputs cannot overflow
+    pub fn prefix_sum(&self) -> DataBuffer<i64> {
+        let name = format!("{}_prefix", self.name);
+        let data: Vec<i64> = self
+            .data
+            .iter()
+            .scan(0i64, |total, &x| {
+                *total += x as i64;
+                Some(*total)
+            })
+            .collect();
+        self.log_event(&format!("  ✓ Allocated new buffer '{}' ({} elements)", name, data.len()));
+        DataBuffer::from_parts(name, data, self.verbose)
+    }
+    
+    /// Differences between neighbours (`data[i + 1] - data[i]`), one element
+    /// shorter than the input; wraps on overflow like `scaled_iter`
+    pub fn diff(&self) -> DataBuffer<i32> {
+        let name = format!("{}_diff", self.name);
+        let data: Vec<i32> = self.data.windows(2).map(|w| w[1].wrapping_sub(w[0])).collect();
+        self.log_event(&format!("  ✓ Allocated new buffer '{}' ({} elements)", name, data.len()));
+        DataBuffer::from_parts(name, data, self.verbose)
+    }
+    
+    /// Mean of each `window`-element run as a new f64 buffer of `len - window + 1`
+    pub fn moving_average(&self, window: usize) -> Result<DataBuffer<f64>, BufferError> {
+        if window == 0 {
+            return Err(BufferError::InvalidArgument("window must be at least 1"));
+        }
+        if window > self.data.len() {
+            return Err(BufferError::InvalidArgument("window must not exceed buffer length"));
+        }
+        let name = format!("{}_smoothed", self.name);
+        let data: Vec<f64> = self
+            .data
+            .windows(window)
+            .map(|w| w.iter().map(|&x| x as i64).sum::<i64>() as f64 / window as f64)
+            .collect();
+        self.log_event(&format!("  ✓ Allocated new buffer '{}' ({} elements)", name, data.len()));
+        Ok(DataBuffer::from_parts(name, data, self.verbose))
+    }
+    
+    /// Valid-mode 1D convolution (kernel flipped, no padding) as a new buffer of
+    /// `len - kernel.len() + 1`, named "{name}_convolved"; each output accumulates
+    /// in i64 and then saturates to the i32 range rather than wrapping
+    pub fn convolve(&self, kernel: &[i32]) -> Result<DataBuffer<i32>, BufferError> {
+        if kernel.is_empty() {
+            return Err(BufferError::InvalidArgument("kernel must not be empty"));
+        }
+        if kernel.len() > self.data.len() {
+            return Err(BufferError::InvalidArgument("kernel must not exceed buffer length"));
+        }
+        let name = format!("{}_convolved", self.name);
+        let data: Vec<i32> = self
+            .data
+            .windows(kernel.len())
+            .map(|w| {
+                let total: i64 = w
+                    .iter()
+                    .zip(kernel.iter().rev())
+                    .map(|(&x, &k)| x as i64 * k as i64)
+                    .sum();
+                total.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+            })
+            .collect();
+        self.log_event(&format!("  ✓ Allocated new buffer '{}' ({} elements)", name, data.len()));
+        Ok(DataBuffer::from_parts(name, data, self.verbose))
+    }
+    
+    /// Indices of strict local maxima: greater than both neighbours, so the
+    /// endpoints and plateaus never count
+    pub fn find_peaks(&self) -> Vec<usize> {
+        self.data
+            .windows(3)
+            .enumerate()
+            .filter(|&(_, w)| w[1] > w[0] && w[1] > w[2])
+            .map(|(i, _)| i + 1)
+            .collect()
+    }
+    
+    /// Maximum of every `k`-element window in O(n): the deque holds indices whose
+    /// values decrease from front to back, so its front is always the window max
+    pub fn window_max(&self, k: usize) -> Result<Vec<i32>, BufferError> {
+        if k == 0 {
+            return Err(BufferError::InvalidArgument("window must be at least 1"));
+        }
+        if k > self.data.len() {
+            return Err(BufferError::InvalidArgument("window must not exceed buffer length"));
+        }
+        let mut candidates: VecDeque<usize> = VecDeque::with_capacity(k);
+        let mut maxima = Vec::with_capacity(self.data.len() - k + 1);
+        for (i, &value) in self.data.iter().enumerate() {
+            // Smaller values behind a new one can never be a window max again
+            while candidates.back().is_some_and(|&j| self.data[j] <= value) {
+                candidates.pop_back();
+            }
+            candidates.push_back(i);
+            if candidates[0] + k <= i {
+                candidates.pop_front();  // Slid out of the window
+            }
+            if i + 1 >= k {
+                maxima.push(self.data[candidates[0]]);
+            }
+        }
+        Ok(maxima)
+    }
+    
+    /// Counts elements per bucket, splitting the integer range `min..=max` into
+    /// `bins` equal-width buckets (the maximum always lands in the last one)
+    pub fn histogram(&self, bins: usize) -> Result<Vec<usize>, BufferError> {
+        if bins == 0 {
+            return Err(BufferError::InvalidArgument("histogram needs at least one bin"));
+        }
+        let stats = self.stats().ok_or(BufferError::EmptyBuffer)?;
+        let span = (stats.max as i64 - stats.min as i64 + 1) as u128;
+        
+        let mut counts = vec![0; bins];
+        for &x in &self.data {
+            let offset = (x as i64 - stats.min as i64) as u128;
+            counts[(offset * bins as u128 / span) as usize] += 1;
+        }
+        Ok(counts)
+    }
+}
+
+/// Summary statistics for an f64 buffer; NaN values are counted, not summarized
+#[derive(Debug, Clone, PartialEq)]
+pub struct FloatStats {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub sum: f64,
+    /// Values that went into min/max/mean/sum
+    pub count: usize,
+    pub nan_count: usize,
+}
+
+/// NaN-aware helpers: `f64` is only `PartialOrd`, so the i32 versions don't apply
+impl DataBuffer<f64> {
+    /// Single pass over the non-NaN values; None if there are none
+    pub fn stats_f64(&self) -> Option<FloatStats> {
+        let nan_count = self.data.iter().filter(|x| x.is_nan()).count();
+        let mut values = self.data.iter().copied().filter(|x| !x.is_nan());
+        let first = values.next()?;
+        let (min, max, sum, count) = values.fold(
+            (first, first, first, 1),
+            |(min, max, sum, count), x| (min.min(x), max.max(x), sum + x, count + 1),
+        );
+        Some(FloatStats {
+            min,
+            max,
+            mean: sum / count as f64,
+            sum,
+            count,
+            nan_count,
+        })
+    }
+    
+    /// Total order via `total_cmp`: -NaN sorts first, +NaN last, -0.0 before 0.0
+    pub fn sort_f64(&mut self) {
+        self.data.sort_by(|a, b| a.total_cmp(b));
+    }
+}
+
+/// Scalar scaling for i32 buffers
+impl DataBuffer<i32> {
+    /// Multiplies every element in place; overflowing elements wrap and are reported
+    pub fn scale(&mut self, factor: i32) {
+        let mut overflowed = 0;
+        for item in self.data.iter_mut() {
+            let (scaled, overflow) = item.overflowing_mul(factor);
+            if overflow {
+                overflowed += 1;
+            }
+            *item = scaled;
+        }
+        if overflowed > 0 {
+            self.log_event(&format!(
+                "  ⚠ {} element(s) in '{}' overflowed and wrapped",
+                overflowed, self.name
+            ));
+        }
+    }
+    
+    /// Lazily yields scaled values (wrapping, like `scale`) without allocating;
+    /// the iterator borrows `self`, so the buffer must outlive it
+    pub fn scaled_iter(&self, factor: i32) -> impl Iterator<Item = i32> + '_ {
+        self.data.iter().map(move |&x| x.wrapping_mul(factor))
+    }
+    
+    /// Like `scale`, but overflowing elements clamp to `i32::MIN`/`i32::MAX`
+    /// instead of wrapping; prints and returns how many were clamped
+    pub fn apply_gain(&mut self, gain: i32) -> usize {
+        let mut clamped = 0;
+        for item in self.data.iter_mut() {
+            if item.checked_mul(gain).is_none() {
+                clamped += 1;
+            }
+            *item = item.saturating_mul(gain);
+        }
+        self.log_event(&format!(
+            "  ✓ Applied gain {} to '{}': {} element(s) clamped",
+            gain, self.name, clamped
+        ));
+        clamped
+    }
+}
+
+/// `buffer * 3` consumes the buffer and returns it scaled (same allocation)
+impl Mul<i32> for DataBuffer<i32> {
+    type Output = DataBuffer<i32>;
+    
+    fn mul(mut self, factor: i32) -> DataBuffer<i32> {
+        self.scale(factor);
+        self
+    }
+}
+
+/// `buffer *= 3` scales in place through a mutable borrow
+impl MulAssign<i32> for DataBuffer<i32> {
+    fn mul_assign(&mut self, factor: i32) {
+        self.scale(factor);
+    }
+}
+
+/// CSV interop: one element per line, so the output is a single-column sheet
+impl DataBuffer<i32> {
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        for value in &self.data {
+            csv.push_str(&value.to_string());
+            csv.push('\n');
+        }
+        csv
+    }
+    
+    /// Parses one integer per line; surrounding whitespace and blank lines are skipped
+    pub fn from_csv(s: &str) -> Result<DataBuffer<i32>, BufferError> {
+        let mut data = Vec::new();
+        for (i, line) in s.lines().enumerate() {
+            let token = line.trim();
+            if token.is_empty() {
+                continue;
+            }
+            let value = token.parse().map_err(|_| BufferError::Parse {
+                line: i + 1,
+                token: token.to_string(),
+            })?;
+            data.push(value);
+        }
+        Ok(DataBuffer::from_parts("csv_import", data, true))
+    }
+}
+
+/// File persistence using the little-endian byte layout
+#[cfg(feature = "std")]
+impl DataBuffer<i32> {
+    pub fn save_to_file(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_bytes_le())?;
+        self.log_event(&format!("  ✓ Saved '{}' to {}", self.name, path.display()));
+        Ok(())
+    }
+    
+    /// The loaded buffer is named after the file stem
+    pub fn load_from_file(path: &Path) -> Result<DataBuffer<i32>, BufferError> {
+        let bytes = fs::read(path)?;
+        let mut buffer = DataBuffer::from_bytes_le(&bytes)?;
+        if let Some(stem) = path.file_stem() {
+            buffer.name = Cow::Owned(stem.to_string_lossy().into_owned());
+        }
+        buffer.log_event(&format!("✓ Loaded '{}' from {}", buffer.name, path.display()));
+        Ok(buffer)
+    }
+}
+
+/// Size of the element-count prefix written before the raw elements
+const LEN_PREFIX_BYTES: usize = 8;
+
+/// Raw byte layout: a u64 element count followed by each i32
+impl DataBuffer<i32> {
+    /// Serializes as little-endian (the native order on x86/ARM)
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(LEN_PREFIX_BYTES + self.data.len() * 4);
+        bytes.extend_from_slice(&(self.data.len() as u64).to_le_bytes());
+        for value in &self.data {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes
+    }
+    
+    /// Serializes as big-endian ("network" byte order)
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(LEN_PREFIX_BYTES + self.data.len() * 4);
+        bytes.extend_from_slice(&(self.data.len() as u64).to_be_bytes());
+        for value in &self.data {
+            bytes.extend_from_slice(&value.to_be_bytes());
+        }
+        bytes
+    }
+    
+    /// Classic `offset  hex bytes  |ascii|` dump of the elements' little-endian bytes
+    /// (the element data only, without the length prefix), 16 bytes per row
+    pub fn hex_dump(&self) -> String {
+        let bytes: Vec<u8> = self.data.iter().flat_map(|value| value.to_le_bytes()).collect();
+        let mut out = String::new();
+        for (row, chunk) in bytes.chunks(16).enumerate() {
+            out.push_str(&format!("{:08x} ", row * 16));
+            for i in 0..16 {
+                if i == 8 {
+                    out.push(' ');
+                }
+                match chunk.get(i) {
+                    Some(byte) => out.push_str(&format!(" {:02x}", byte)),
+                    None => out.push_str("   "),
+                }
+            }
+            out.push_str("  |");
+            for &byte in chunk {
+                out.push(if (0x20..=0x7e).contains(&byte) { byte as char } else { '.' });
+            }
+            out.push_str("|\n");
+        }
+        out
+    }
+    
+    /// Parses little-endian bytes; never indexes past the end of the slice
+    /// (trailing bytes beyond the declared length are ignored)
+    pub fn from_bytes_le(bytes: &[u8]) -> Result<DataBuffer<i32>, BufferError> {
+        if bytes.len() < LEN_PREFIX_BYTES {
+            return Err(BufferError::Truncated {
+                expected: LEN_PREFIX_BYTES,
+                actual: bytes.len(),
+            });
+        }
+        let (prefix, body) = bytes.split_at(LEN_PREFIX_BYTES);
+        let mut len_bytes = [0u8; LEN_PREFIX_BYTES];
+        len_bytes.copy_from_slice(prefix);
+        let declared = u64::from_le_bytes(len_bytes);
+        
+        if declared > MAX_BUFFER_SIZE as u64 {
+            return Err(BufferError::TooLarge {
+                requested: declared as usize,
+                max: MAX_BUFFER_SIZE,
+            });
+        }
+        let count = declared as usize;
+        if body.len() < count * 4 {
+            return Err(BufferError::Truncated {
+                expected: LEN_PREFIX_BYTES + count * 4,
+                actual: bytes.len(),
+            });
+        }
+        
+        let data = body
+            .chunks_exact(4)
+            .take(count)
+            .map(|chunk| i32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect();
+        Ok(DataBuffer::from_parts("decoded", data, true))
+    }
+}
+
+/// Run-length encoding: consecutive equal values collapse to `(value, count)`
+impl DataBuffer<i32> {
+    pub fn rle_encode(&self) -> Vec<(i32, usize)> {
+        let mut runs: Vec<(i32, usize)> = Vec::new();
+        for &x in &self.data {
+            match runs.last_mut() {
+                Some(&mut (value, ref mut count)) if value == x => *count += 1,
+                _ => runs.push((x, 1)),
+            }
+        }
+        runs
+    }
+    
+    /// Rebuilds a buffer from encoded runs; like `from_bytes_le`, the total
+    /// length is checked against `MAX_BUFFER_SIZE` before anything is allocated
+    pub fn rle_decode(runs: &[(i32, usize)]) -> Result<DataBuffer<i32>, BufferError> {
+        let total = runs
+            .iter()
+            .try_fold(0usize, |acc, &(_, count)| acc.checked_add(count))
+            .unwrap_or(usize::MAX);
+        if total > MAX_BUFFER_SIZE {
+            return Err(BufferError::TooLarge {
+                requested: total,
+                max: MAX_BUFFER_SIZE,
+            });
+        }
+        let mut data = Vec::with_capacity(total);
+        for &(value, count) in runs {
+            let end = data.len() + count;
+            data.resize(end, value);
+        }
+        Ok(DataBuffer::from_parts("rle_decoded", data, true))
+    }
+}
+
+// When DataBuffer goes out of scope, this is called
+impl<T> Drop for DataBuffer<T> {
+    fn drop(&mut self) {
+        self.log_event(&format!("  ✗ Dropping buffer '{}' - memory freed", self.name));
+    }
+}
+
+/// Empty, unallocated buffer named "default"
+impl<T> Default for DataBuffer<T> {
+    fn default() -> Self {
+        DataBuffer::new_empty("default")
+    }
+}
+
+/// `buffer.extend(iter)` - takes ownership of each item the iterator yields
+impl<T> Extend<T> for DataBuffer<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.data.extend(iter);
+    }
+}
+
+/// `iter.collect::<DataBuffer<_>>()` - named after how many items were collected
+impl<T> FromIterator<T> for DataBuffer<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let data: Vec<T> = iter.into_iter().collect();
+        let name = format!("collected_{}", data.len());
+        let buffer = DataBuffer::from_parts(name, data, true);
+        buffer.log_event(&format!("✓ Collected {} items into '{}'", buffer.data.len(), buffer.name));
+        buffer
+    }
+}
+
+/// Zero-copy wrap: the vector's allocation moves into the buffer
+impl<T> From<Vec<T>> for DataBuffer<T> {
+    fn from(data: Vec<T>) -> Self {
+        let name = format!("buffer_{}", data.len());
+        let buffer = DataBuffer::from_parts(name, data, true);
+        buffer.log_event(&format!("✓ Wrapping existing vector as '{}' (no copy)", buffer.name));
+        buffer
+    }
+}
+
+/// Zero-copy unwrap: the allocation moves out, then the empty shell is dropped
+impl<T> From<DataBuffer<T>> for Vec<T> {
+    fn from(mut buffer: DataBuffer<T>) -> Self {
+        buffer.log_event(&format!("  ↘ Buffer '{}' handing its vector to the caller", buffer.name));
+        core::mem::take(&mut buffer.data)
+    }
+}
+
+/// Explicit copy from a borrowed slice (the source keeps its own allocation)
+impl<'a, T: Clone> TryFrom<&'a [T]> for DataBuffer<T> {
+    type Error = BufferError;
+    
+    fn try_from(values: &'a [T]) -> Result<Self, BufferError> {
+        if values.is_empty() {
+            return Err(BufferError::EmptyBuffer);
+        }
+        let name = format!("slice_copy_{}", values.len());
+        let buffer = DataBuffer::from_parts(name, values.to_vec(), true);
+        buffer.log_event(&format!("✓ Copying {} elements from slice into '{}'", values.len(), buffer.name));
+        Ok(buffer)
+    }
+}
+
+/// Read-only slice view: `buffer.iter()`, `buffer.first()`, `&buffer` as `&[T]`
+///
+/// Inherent methods always win over deref'd slice methods with the same name,
+/// so `buffer.len()` calls `DataBuffer::len` and `buffer.get(i)` returns the
+/// checked `Result` rather than the slice's `Option`.
+impl<T> Deref for DataBuffer<T> {
+    type Target = [T];
+    
+    fn deref(&self) -> &[T] {
+        &self.data
+    }
+}
+
+impl<T> AsRef<[T]> for DataBuffer<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.data
+    }
+}
+
+impl<T> AsMut<[T]> for DataBuffer<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+}
+
+/// `buffer[i]`, `buffer[1..3]`, `buffer[2..]`, ... exactly as on a `Vec`:
+/// out-of-range panics, unlike the checked `get` / `set` which return an error
+impl<T, I: SliceIndex<[T]>> Index<I> for DataBuffer<T> {
+    type Output = I::Output;
+    
+    fn index(&self, index: I) -> &I::Output {
+        &self.data[index]
+    }
+}
+
+/// `buffer[i] = value` and `&mut buffer[a..b]`; panics out of range like `Index`
+impl<T, I: SliceIndex<[T]>> IndexMut<I> for DataBuffer<T> {
+    fn index_mut(&mut self, index: I) -> &mut I::Output {
+        &mut self.data[index]
+    }
+}
+
+/// Content equality - names and metadata tags are decorative and ignored
+impl<T: PartialEq> PartialEq for DataBuffer<T> {
+    fn eq(&self, other: &DataBuffer<T>) -> bool {
+        self.data == other.data
+    }
+}
+
+impl<T: Eq> Eq for DataBuffer<T> {}
+
+/// Orders lexicographically by `data` (shorter prefix sorts first), ignoring names
+impl<T: PartialOrd> PartialOrd for DataBuffer<T> {
+    fn partial_cmp(&self, other: &DataBuffer<T>) -> Option<core::cmp::Ordering> {
+        self.data.partial_cmp(&other.data)
+    }
+}
+
+impl<T: Ord> Ord for DataBuffer<T> {
+    fn cmp(&self, other: &DataBuffer<T>) -> core::cmp::Ordering {
+        self.data.cmp(&other.data)
+    }
+}
+
+/// Hashes only `data`, consistent with `PartialEq` ignoring names
+impl<T: Hash> Hash for DataBuffer<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+    }
+}
+
+impl<T: PartialEq> DataBuffer<T> {
+    /// Currently identical to `==`; reserved for a tolerance-based float comparison
+    pub fn approx_eq(&self, other: &DataBuffer<T>) -> bool {
+        self == other
+    }
+}
+
+/// Maximum number of elements listed by `Display` before truncating
+const DISPLAY_LIMIT: usize = 16;
+
+/// Side-effect-free formatting, e.g. `Buffer 'name' [1, 2, 3] (3/4 elements)`
+impl<T: Display> Display for DataBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Buffer '{}' [", self.name)?;
+        for (i, value) in self.data.iter().take(DISPLAY_LIMIT).enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", value)?;
+        }
+        if self.data.len() > DISPLAY_LIMIT {
+            write!(f, ", ...")?;
+        }
+        write!(f, "] ({}/{} elements)", self.data.len(), self.data.capacity())
+    }
+}
+
+/// Explicit deep copy - allocates a second vector (contrast with a move)
+impl<T: Clone> Clone for DataBuffer<T> {
+    fn clone(&self) -> Self {
+        self.log_event(&format!("  ↗ Deep-copying buffer '{}' ({} elements)", self.name, self.data.len()));
+        let name = format!("{}_clone", self.name);
+        let mut copy = DataBuffer::from_parts(name, self.data.clone(), self.verbose);
+        copy.generation = self.generation + 1;
+        copy.metadata = self.metadata.clone();
+        copy
+    }
+}
+
+/// Owning iterator - keeps the (emptied) buffer alive until iteration ends
+pub struct BufferIntoIter<T> {
+    inner: alloc::vec::IntoIter<T>,
+    _buffer: DataBuffer<T>,
+}
+
+impl<T> Iterator for BufferIntoIter<T> {
+    type Item = T;
+    
+    fn next(&mut self) -> Option<T> {
+        self.inner.next()
+    }
+    
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// `.rev()` and `.next_back()`, as on `vec::IntoIter`
+impl<T> DoubleEndedIterator for BufferIntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.inner.next_back()
+    }
+}
+
+/// `.len()` - the exact count comes from the inner iterator's `size_hint`
+impl<T> ExactSizeIterator for BufferIntoIter<T> {}
+
+impl<T> FusedIterator for BufferIntoIter<T> {}
+
+/// Consumes the buffer: `for x in buffer` takes ownership of the elements
+impl<T> IntoIterator for DataBuffer<T> {
+    type Item = T;
+    type IntoIter = BufferIntoIter<T>;
+    
+    fn into_iter(mut self) -> Self::IntoIter {
+        // Drop prevents moving `data` out directly, so take it and leave an empty Vec
+        let data = core::mem::take(&mut self.data);
+        BufferIntoIter {
+            inner: data.into_iter(),
+            _buffer: self,
+        }
+    }
+}
+
+/// Borrows immutably: `for x in &buffer` yields `&T`
+impl<'a, T> IntoIterator for &'a DataBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+/// Borrows mutably: `for x in &mut buffer` yields `&mut T`
+impl<'a, T> IntoIterator for &'a mut DataBuffer<T> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
+
+/// Chained construction: each method takes `self` by value and moves it onward
+#[derive(Default)]
+pub struct DataBufferBuilder {
+    name: Option<Cow<'static, str>>,
+    size: usize,
+    fill_start: Option<i32>,
+}
+
+impl DataBufferBuilder {
+    pub fn new() -> Self {
+        DataBufferBuilder {
+            name: None,
+            size: 0,
+            fill_start: None,
+        }
+    }
+    
+    pub fn name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+    
+    pub fn size(mut self, size: usize) -> Self {
+        self.size = size;
+        self
+    }
+    
+    pub fn fill_start(mut self, start: i32) -> Self {
+        self.fill_start = Some(start);
+        self
+    }
+    
+    /// Validates the size (via `try_new`) and applies the fill if one was requested
+    /// A missing name defaults to "unnamed"
+    pub fn build(self) -> Result<DataBuffer<i32>, BufferError> {
+        let name = self.name.unwrap_or(Cow::Borrowed("unnamed"));
+        let mut buffer = DataBuffer::try_new(name, self.size)?;
+        if let Some(start) = self.fill_start {
+            buffer.fill_with_values(start);
+        }
+        Ok(buffer)
+    }
+}
+
+/// Owns many buffers, keyed by each buffer's name
+pub struct BufferManager<T = i32> {
+    buffers: Map<String, DataBuffer<T>>,
+}
+
+impl<T> BufferManager<T> {
+    pub fn new() -> Self {
+        BufferManager {
+            buffers: Map::new(),
+        }
+    }
+    
+    /// Takes ownership of the buffer (replacing any buffer with the same name)
+    pub fn insert(&mut self, buffer: DataBuffer<T>) {
+        buffer.log_event(&format!("  → Manager took ownership of '{}'", buffer.name));
+        self.buffers.insert(buffer.name.to_string(), buffer);
+    }
+    
+    /// Lends out a buffer without giving up ownership
+    pub fn get(&self, name: &str) -> Option<&DataBuffer<T>> {
+        self.buffers.get(name)
+    }
+    
+    /// Hands ownership of the buffer back to the caller
+    pub fn remove(&mut self, name: &str) -> Option<DataBuffer<T>> {
+        self.buffers.remove(name)
+    }
+    
+    /// Total number of elements across all owned buffers
+    pub fn total_elements(&self) -> usize {
+        self.buffers.values().map(|buffer| buffer.data.len()).sum()
+    }
+}
+
+impl<T> Default for BufferManager<T> {
+    fn default() -> Self {
+        BufferManager::new()
+    }
+}
+
+/// Recycles released buffers so their allocations can be reused
+pub struct BufferPool<T = i32> {
+    free: Vec<DataBuffer<T>>,
+}
+
+impl<T: Clone + Default> BufferPool<T> {
+    pub fn new() -> Self {
+        BufferPool { free: Vec::new() }
+    }
+    
+    /// Hands out a zeroed buffer, reusing a pooled allocation when one is available
+    /// The size is validated like `DataBuffer::try_new`, whichever path is taken
+    pub fn acquire(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        size: usize,
+    ) -> Result<DataBuffer<T>, BufferError> {
+        check_size(size, MAX_BUFFER_SIZE)?;
+        let name = name.into();
+        match self.free.pop() {
+            Some(mut buffer) => {
+                buffer.data.clear();
+                buffer.data.resize(size, T::default());
+                buffer.log_event(&format!(
+                    "  ♻ Reusing allocation of '{}' for '{}' (capacity {})",
+                    buffer.name,
+                    name,
+                    buffer.data.capacity()
+                ));
+                buffer.name = name;
+                // Nothing from the previous owner carries over: tags, age or generation
+                buffer.metadata.clear();
+                #[cfg(feature = "std")]
+                {
+                    buffer.created_at = Instant::now();
+                }
+                buffer.generation = 0;
+                Ok(buffer)
+            }
+            None => {
+                let buffer = DataBuffer::try_new(name, size)?;
+                buffer.log_event(&format!("  ✚ Pool was empty, allocated '{}' fresh", buffer.name));
+                Ok(buffer)
+            }
+        }
+    }
+    
+    /// Takes the buffer back instead of letting it be freed
+    pub fn release(&mut self, buffer: DataBuffer<T>) {
+        buffer.log_event(&format!("  ↩ Returned '{}' to the pool", buffer.name));
+        self.free.push(buffer);
+    }
+}
+
+impl<T: Clone + Default> Default for BufferPool<T> {
+    fn default() -> Self {
+        BufferPool::new()
+    }
+}
+
+/// Fixed-capacity ring buffer: once full, each push overwrites the oldest element
+/// Memory usage is bounded - the backing vector never grows past `capacity`
+pub struct CircularBuffer<T = i32> {
+    data: Vec<T>,
+    head: usize,  // Index of the oldest element once the buffer is full
+    capacity: usize,
+}
+
+impl<T> CircularBuffer<T> {
+    /// Allocates the full backing storage up front; panics if `capacity` is 0
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "ring buffer capacity must be greater than zero");
+        CircularBuffer {
+            data: Vec::with_capacity(capacity),
+            head: 0,
+            capacity,
+        }
+    }
+    
+    /// Appends a value, overwriting the oldest one when full
+    pub fn push(&mut self, value: T) {
+        if self.data.len() < self.capacity {
+            self.data.push(value);
+        } else {
+            self.data[self.head] = value;  // Old value dropped in place
+            self.head = (self.head + 1) % self.capacity;
+        }
+    }
+    
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+    
+    /// Yields elements from oldest to newest
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let (newer, older) = self.data.split_at(self.head);
+        older.iter().chain(newer.iter())
+    }
+}
+
+/// Double-ended queue: pushing or popping at either end is O(1), where
+/// inserting at the front of a `DataBuffer` shifts every element
+pub struct DequeBuffer<T = i32> {
+    data: VecDeque<T>,
+}
+
+impl<T> DequeBuffer<T> {
+    pub fn new() -> Self {
+        DequeBuffer { data: VecDeque::new() }
+    }
+    
+    pub fn push_front(&mut self, value: T) {
+        self.data.push_front(value);
+    }
+    
+    pub fn push_back(&mut self, value: T) {
+        self.data.push_back(value);
+    }
+    
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.data.pop_front()
+    }
+    
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.data.pop_back()
+    }
+    
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+    
+    /// Yields elements from front to back
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+    
+    /// Takes ownership (consumes the deque)
+    #[must_use]
+    pub fn into_sum(self) -> T
+    where
+        T: Sum,
+    {
+        self.data.into_iter().sum()
+    }
+}
+
+impl<T> Default for DequeBuffer<T> {
+    fn default() -> Self {
+        DequeBuffer::new()
+    }
+}
+
+/// Reuses the buffer's allocation: the vector moves out and becomes the deque
+impl<T> From<DataBuffer<T>> for DequeBuffer<T> {
+    fn from(buffer: DataBuffer<T>) -> Self {
+        DequeBuffer {
+            data: VecDeque::from(Vec::from(buffer)),
+        }
+    }
+}
+
+/// Small-buffer optimization: up to `N` elements live inline (no heap allocation);
+/// pushing one more moves everything into a `Vec` for good
+pub enum SmallBuffer<T, const N: usize> {
+    Inline { items: [T; N], len: usize },
+    Heap(Vec<T>),
+}
+
+impl<T: Copy + Default, const N: usize> SmallBuffer<T, N> {
+    pub fn new() -> Self {
+        SmallBuffer::Inline {
+            items: [T::default(); N],
+            len: 0,
+        }
+    }
+    
+    /// Writes inline while there is room, otherwise spills to the heap first
+    pub fn push(&mut self, value: T) {
+        match *self {
+            SmallBuffer::Inline { ref mut items, ref mut len } if *len < N => {
+                items[*len] = value;
+                *len += 1;
+            }
+            SmallBuffer::Inline { ref items, len } => {
+                let mut spilled = Vec::with_capacity(len * 2 + 1);
+                spilled.extend_from_slice(&items[..len]);
+                spilled.push(value);
+                *self = SmallBuffer::Heap(spilled);
+            }
+            SmallBuffer::Heap(ref mut vec) => vec.push(value),
+        }
+    }
+    
+    pub fn len(&self) -> usize {
+        match *self {
+            SmallBuffer::Inline { len, .. } => len,
+            SmallBuffer::Heap(ref vec) => vec.len(),
+        }
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    
+    pub fn as_slice(&self) -> &[T] {
+        match *self {
+            SmallBuffer::Inline { ref items, len } => &items[..len],
+            SmallBuffer::Heap(ref vec) => vec,
+        }
+    }
+    
+    pub fn is_inline(&self) -> bool {
+        match *self {
+            SmallBuffer::Inline { .. } => true,
+            SmallBuffer::Heap(_) => false,
+        }
+    }
+}
+
+impl<T: Copy + Default, const N: usize> Default for SmallBuffer<T, N> {
+    fn default() -> Self {
+        SmallBuffer::new()
+    }
+}
+
+/// Stack-only buffer whose length is part of its type, so `size_of` is known at compile time
+pub struct FixedBuffer<const N: usize> {
+    data: [i32; N],
+}
+
+impl<const N: usize> FixedBuffer<N> {
+    pub fn new() -> Self {
+        FixedBuffer { data: [0; N] }
+    }
+    
+    /// Same sequence as `DataBuffer::fill_with_values`: start, start + 1, ...
+    pub fn fill_with_values(&mut self, start: i32) {
+        for (i, item) in self.data.iter_mut().enumerate() {
+            *item = start + i as i32;
+        }
+    }
+    
+    /// Widened to i64 so a full buffer cannot overflow
+    pub fn sum(&self) -> i64 {
+        self.data.iter().map(|&x| x as i64).sum()
+    }
+}
+
+impl<const N: usize> Default for FixedBuffer<N> {
+    fn default() -> Self {
+        FixedBuffer::new()
+    }
+}
+
+/// Arithmetic buffer for any `num_traits::Num` type (i64, f32, f64, Complex, ...)
+/// `fill_with_values`, `sum` and `dot` need only `Num`; `norm` also needs `Float`
+#[cfg(feature = "num-traits")]
+pub struct NumBuffer<T> {
+    data: Vec<T>,
+}
+
+#[cfg(feature = "num-traits")]
+impl<T: Num + Copy> NumBuffer<T> {
+    /// `size` zeros
+    pub fn new(size: usize) -> Self {
+        NumBuffer {
+            data: vec![T::zero(); size],
+        }
+    }
+    
+    /// start, start + 1, ... built by repeatedly adding `T::one()`, so no cast is needed
+    pub fn fill_with_values(&mut self, start: T) {
+        let mut next = start;
+        for item in self.data.iter_mut() {
+            *item = next;
+            next = next + T::one();
+        }
+    }
+    
+    /// Sums in `T` itself - unlike `DataBuffer::wide_sum` there is no wider type to use
+    pub fn sum(&self) -> T {
+        self.data.iter().fold(T::zero(), |acc, &x| acc + x)
+    }
+    
+    pub fn dot(&self, other: &NumBuffer<T>) -> Result<T, BufferError> {
+        if self.data.len() != other.data.len() {
+            return Err(BufferError::LengthMismatch {
+                left: self.data.len(),
+                right: other.data.len(),
+            });
+        }
+        Ok(self
+            .data
+            .iter()
+            .zip(other.data.iter())
+            .fold(T::zero(), |acc, (&a, &b)| acc + a * b))
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T: Float> NumBuffer<T> {
+    /// Euclidean length; requires `Float` for `sqrt`
+    pub fn norm(&self) -> T {
+        self.data.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt()
+    }
+}
+
+/// Demonstrates borrowing (read-only)
+pub fn process_buffer(buffer: &DataBuffer) -> i32 {
+    buffer.log_event(&format!("  Processing buffer '{}'...", buffer.name));
+    buffer.count_where(|&x| x > 0) as i32
+}
+
+/// Accepts any i32 slice - a &DataBuffer deref-coerces into one
+pub fn sum_slice(values: &[i32]) -> i32 {
+    values.iter().sum()
+}
+
+/// Consumes every buffer from the iterator into one buffer named "concat"
+/// Each input is dropped as soon as its elements have been moved over
+pub fn concat<T>(buffers: impl IntoIterator<Item = DataBuffer<T>>) -> DataBuffer<T> {
+    let mut combined = DataBuffer::from_parts("concat", Vec::new(), true);
+    for buffer in buffers {
+        combined.append(buffer);
+    }
+    combined
+}
+
+/// Demonstrates mutable borrowing
+pub fn modify_buffer(buffer: &mut DataBuffer, multiplier: i32) {
+    buffer.scale(multiplier);
+    buffer.log_event(&format!("  ✓ Modified buffer '{}'", buffer.name));
+}
+
+/// Stack size of every DataBuffer field; the exhaustive pattern stops compiling
+/// when a field is added, so the list cannot fall behind the struct
+#[cfg(feature = "std")]
+fn field_sizes<T>(buffer: &DataBuffer<T>) -> [(&'static str, usize); 6] {
+    let DataBuffer {
+        data,
+        name,
+        verbose,
+        created_at,
+        generation,
+        metadata,
+    } = buffer;
+    [
+        ("data", std::mem::size_of_val(data)),
+        ("name", std::mem::size_of_val(name)),
+        ("verbose", std::mem::size_of_val(verbose)),
+        ("created_at", std::mem::size_of_val(created_at)),
+        ("generation", std::mem::size_of_val(generation)),
+        ("metadata", std::mem::size_of_val(metadata)),
+    ]
+}
+
+/// Prints stack size and alignment of common owners, and where their contents live
+#[cfg(feature = "std")]
+pub fn explain_layout() {
+    fn row<T>(label: &str, note: &str) {
+        println!(
+            "  {:<16} size {:>3}, align {:>2} - {}",
+            label,
+            std::mem::size_of::<T>(),
+            std::mem::align_of::<T>(),
+            note
+        );
+    }
+    row::<[i32; 4]>("[i32; 4]", "all four elements inline on the stack");
+    row::<Box<i32>>("Box<i32>", "one pointer; the i32 is on the heap");
+    row::<Vec<i32>>("Vec<i32>", "pointer + capacity + length; elements on the heap");
+    row::<&DataBuffer>("&DataBuffer", "one pointer to a buffer owned elsewhere");
+    row::<DataBuffer>("DataBuffer", "handles for data, name and tags; contents on the heap");
+    
+    // Any gap between the field total and the struct size is alignment padding
+    let probe: DataBuffer = DataBuffer::from_parts("layout", Vec::new(), false);
+    let fields: usize = field_sizes(&probe).iter().map(|&(_, size)| size).sum();
+    println!(
+        "  DataBuffer fields sum to {} bytes, {} bytes of padding",
+        fields,
+        std::mem::size_of::<DataBuffer>() - fields
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    // Only the buffer core, so this also runs in the alloc-only build
+    #[test]
+    fn core_works_without_std() {
+        let mut buffer: DataBuffer = DataBuffer::new_quiet("core", 4);
+        buffer.fill_with_values(1);
+        buffer.set_meta("kind", "core");
+        assert_eq!(buffer.get_meta("kind"), Some("core"));
+        assert!(buffer.info_string().contains("core"));
+        assert_eq!(buffer.into_sum(), 1 + 2 + 3 + 4);
+    }
+    
+    #[test]
+    fn get_and_set_check_bounds() {
+        let mut buffer: DataBuffer = DataBuffer::new_quiet("bounds", 5);
+        buffer.fill_with_values(10);
+        
+        assert_eq!(buffer.get(2).unwrap(), 12);
+        buffer.set(2, 99).unwrap();
+        assert_eq!(buffer.get(2).unwrap(), 99);
+        
+        match buffer.get(5) {
+            Err(BufferError::OutOfBounds { index: 5, len: 5 }) => {}
+            other => panic!("expected OutOfBounds at len, got {:?}", other),
+        }
+        match buffer.set(1000, 1) {
+            Err(BufferError::OutOfBounds { index: 1000, len: 5 }) => {}
+            other => panic!("expected OutOfBounds far past len, got {:?}", other),
+        }
+        assert_eq!(buffer.get(4).unwrap(), 14);  // Failed set left the data alone
+    }
+    
+    #[test]
+    fn try_new_rejects_zero_and_oversized() {
+        match DataBuffer::<i32>::try_new("zero", 0) {
+            Err(BufferError::EmptyBuffer) => {}
+            other => panic!("expected EmptyBuffer, got {:?}", other),
+        }
+        match DataBuffer::<i32>::try_new_with_max("big", 11, 10) {
+            Err(BufferError::TooLarge { requested: 11, max: 10 }) => {}
+            other => panic!("expected TooLarge, got {:?}", other),
+        }
+        assert!(DataBuffer::<i32>::try_new("huge", MAX_BUFFER_SIZE + 1).is_err());
+        assert_eq!(DataBuffer::<i32>::try_new_with_max("edge", 10, 10).unwrap().len(), 10);
+    }
+    
+    #[test]
+    fn sums_handle_overflow() {
+        let buffer: DataBuffer = vec![i32::MAX, 1].into_iter().collect();
+        assert_eq!(buffer.checked_sum(), None);
+        assert_eq!(buffer.saturating_sum(), i32::MAX);
+        assert_eq!(buffer.wide_sum(), i32::MAX as i64 + 1);
+        
+        let negative: DataBuffer = vec![i32::MIN, -1].into_iter().collect();
+        assert_eq!(negative.checked_sum(), None);
+        assert_eq!(negative.saturating_sum(), i32::MIN);
+        
+        let small: DataBuffer = vec![1, 2, 3].into_iter().collect();
+        assert_eq!(small.checked_sum(), Some(6));
+        assert_eq!(small.saturating_sum(), 6);
+    }
+    
+    #[test]
+    fn manager_round_trips_buffers() {
+        let mut manager: BufferManager = BufferManager::new();
+        let mut first = DataBuffer::new_quiet("first", 3);
+        first.fill_with_values(1);
+        manager.insert(first);
+        manager.insert(DataBuffer::new_quiet("second", 4));
+        
+        assert_eq!(manager.total_elements(), 7);
+        assert_eq!(manager.get("first").map(|b| b.len()), Some(3));
+        assert!(manager.get("missing").is_none());
+        
+        let owned = manager.remove("first").unwrap();
+        assert_eq!(owned.into_sum(), 6);
+        assert!(manager.get("first").is_none());
+        assert!(manager.remove("first").is_none());
+        assert_eq!(manager.total_elements(), 4);
+        
+        // Same name replaces the earlier buffer
+        manager.insert(DataBuffer::new_quiet("second", 1));
+        assert_eq!(manager.total_elements(), 1);
+    }
+    
+    #[test]
+    fn byte_math_for_known_size() {
+        let buffer: DataBuffer = DataBuffer::new_quiet("bytes", 10);
+        assert_eq!(buffer.heap_bytes(), 10 * 4);  // Literal name costs no heap
+        assert_eq!(
+            buffer.size_in_bytes(),
+            10 * 4 + core::mem::size_of::<DataBuffer>()
+        );
+        
+        let mut owned: DataBuffer = DataBuffer::new_quiet(String::from("abc"), 10);
+        owned.push(1);  // Grows capacity past the length
+        let name_bytes = match owned.name {
+            Cow::Owned(ref name) => name.capacity(),
+            Cow::Borrowed(_) => panic!("expected an owned name"),
+        };
+        assert!(owned.capacity() > owned.len());
+        assert_eq!(owned.heap_bytes(), owned.capacity() * 4 + name_bytes);
+    }
+    
+    #[test]
+    fn rc_strong_count_rises_and_falls() {
+        use alloc::rc::Rc;
+        
+        let first = Rc::new(DataBuffer::<i32>::new_quiet("shared", 3));
+        assert_eq!(Rc::strong_count(&first), 1);
+        let second = Rc::clone(&first);
+        assert_eq!(Rc::strong_count(&first), 2);
+        {
+            let third = Rc::clone(&second);
+            assert_eq!(Rc::strong_count(&third), 3);
+        }
+        assert_eq!(Rc::strong_count(&first), 2);
+        drop(first);
+        assert_eq!(Rc::strong_count(&second), 1);
+        assert!(Rc::try_unwrap(second).is_ok());  // Last owner gets the buffer back
+    }
+    
+    #[cfg(feature = "std")]
+    #[test]
+    fn arc_threads_match_single_threaded_count() {
+        use std::sync::Arc;
+        
+        let mut buffer: DataBuffer = DataBuffer::new_quiet("arc", 20);
+        buffer.fill_with_values(-5);
+        let expected = process_buffer(&buffer) * 4;
+        
+        let shared = Arc::new(buffer);
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let buffer = Arc::clone(&shared);
+                thread::spawn(move || process_buffer(&buffer))
+            })
+            .collect();
+        let total: i32 = handles.into_iter().map(|h| h.join().unwrap()).sum();
+        assert_eq!(total, expected);
+        assert_eq!(expected, 14 * 4);  // -5..=14 has 14 positive values
+    }
+    
+    #[cfg(feature = "std")]
+    #[test]
+    fn mutex_increments_sum_across_threads() {
+        use std::sync::{Arc, Mutex};
+        
+        let shared = Arc::new(Mutex::new(DataBuffer::<i32>::new_quiet("locked", 3)));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let local = Arc::clone(&shared);
+                thread::spawn(move || {
+                    for _ in 0..100 {
+                        let mut guard = match local.lock() {
+                            Ok(guard) => guard,
+                            Err(poisoned) => poisoned.into_inner(),
+                        };
+                        for item in guard.data.iter_mut() {
+                            *item += 1;
+                        }
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        let guard = shared.lock().unwrap();
+        assert_eq!(guard.data, vec![400, 400, 400]);
+        assert_eq!(guard.wide_sum(), 3 * 400);
+    }
+    
+    #[test]
+    fn refcell_rejects_second_mutable_borrow() {
+        use alloc::rc::Rc;
+        use core::cell::RefCell;
+        
+        let shared = Rc::new(RefCell::new(DataBuffer::<i32>::new_quiet("cell", 4)));
+        let alias = Rc::clone(&shared);
+        {
+            let mut first = shared.borrow_mut();
+            first.fill_with_values(5);
+            assert!(alias.try_borrow_mut().is_err());
+            assert!(alias.try_borrow().is_err());
+        }
+        let mut second = alias.try_borrow_mut().expect("first borrow was released");
+        second.scale(2);
+        drop(second);
+        assert_eq!(shared.borrow().data, vec![10, 12, 14, 16]);
+    }
+    
+    #[test]
+    fn bytes_round_trip_little_endian() {
+        let original: DataBuffer = vec![0, 1, -1, i32::MAX, i32::MIN].into_iter().collect();
+        let bytes = original.to_bytes_le();
+        assert_eq!(bytes.len(), LEN_PREFIX_BYTES + 5 * 4);
+        assert_eq!(DataBuffer::from_bytes_le(&bytes).unwrap(), original);
+        
+        // Same values, opposite byte order within each element
+        let be = original.to_bytes_be();
+        assert_eq!(be[LEN_PREFIX_BYTES + 4..LEN_PREFIX_BYTES + 8], [0, 0, 0, 1]);
+        assert_eq!(bytes[LEN_PREFIX_BYTES + 4..LEN_PREFIX_BYTES + 8], [1, 0, 0, 0]);
+    }
+    
+    #[test]
+    fn truncated_bytes_are_an_error() {
+        let bytes = DataBuffer::from(vec![1, 2, 3]).to_bytes_le();
+        match DataBuffer::from_bytes_le(&bytes[..bytes.len() - 1]) {
+            Err(BufferError::Truncated { expected: 20, actual: 19 }) => {}
+            other => panic!("expected a truncated body, got {:?}", other),
+        }
+        match DataBuffer::from_bytes_le(&bytes[..3]) {
+            Err(BufferError::Truncated { expected: 8, actual: 3 }) => {}
+            other => panic!("expected a truncated prefix, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn display_lists_short_and_truncates_long() {
+        let mut short: DataBuffer = DataBuffer::new_quiet("short", 3);
+        short.fill_with_values(1);
+        assert_eq!(format!("{}", short), "Buffer 'short' [1, 2, 3] (3/3 elements)");
+        
+        let long: DataBuffer = DataBuffer::from_parts("long", (0..100).collect(), false);
+        let text = format!("{}", long);
+        assert!(text.starts_with("Buffer 'long' [0, 1, 2,"));
+        assert!(text.contains(", 15, ...]"));
+        assert!(!text.contains("16"));
+        assert!(text.ends_with(&format!("] (100/{} elements)", long.capacity())));
+    }
+    
+    #[test]
+    fn add_and_sub_elementwise() {
+        let a = DataBuffer::from_parts("a", vec![1, 2, 3], false);
+        let b = DataBuffer::from_parts("b", vec![10, 20, 30], false);
+        let sum = a + b;
+        assert_eq!(sum.data, vec![11, 22, 33]);
+        assert_eq!(sum.name(), "a+b");
+        
+        let c = DataBuffer::from_parts("c", vec![1, 1, 1], false);
+        let diff = sum.sub_checked(c).unwrap();
+        assert_eq!(diff.data, vec![10, 21, 32]);
+        assert_eq!(diff.name(), "a+b-c");
+    }
+    
+    #[test]
+    fn add_checked_rejects_length_mismatch() {
+        let a = DataBuffer::from_parts("a", vec![1, 2, 3], false);
+        let b = DataBuffer::from_parts("b", vec![1, 2], false);
+        match a.add_checked(b) {
+            Err(BufferError::LengthMismatch { left: 3, right: 2 }) => {}
+            other => panic!("expected LengthMismatch, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn scaling_paths_agree() {
+        let values = vec![-3, 0, 7, i32::MAX];
+        let consumed = DataBuffer::from_parts("mul", values.clone(), false) * 3;
+        
+        let mut assigned = DataBuffer::from_parts("assign", values.clone(), false);
+        assigned *= 3;
+        
+        let mut scaled = DataBuffer::from_parts("scale", values, false);
+        scaled.scale(3);
+        
+        assert_eq!(consumed, assigned);
+        assert_eq!(assigned, scaled);
+        assert_eq!(scaled.data[..3], [-9, 0, 21]);
+        assert_eq!(scaled.data[3], i32::MAX.wrapping_mul(3));  // Overflow wraps
+    }
+    
+    #[test]
+    fn stats_for_known_and_empty() {
+        let buffer = DataBuffer::from_parts("stats", vec![4, -2, 10, 0], false);
+        let stats = buffer.stats().unwrap();
+        assert_eq!(stats.min, -2);
+        assert_eq!(stats.max, 10);
+        assert_eq!(stats.sum, 12);
+        assert_eq!(stats.mean, 3.0);
+        
+        let big = DataBuffer::from_parts("big", vec![i32::MAX, i32::MAX], false);
+        assert_eq!(big.stats().unwrap().sum, 2 * i32::MAX as i64);
+        
+        let empty: DataBuffer = DataBuffer::from_parts("empty", Vec::new(), false);
+        assert_eq!(empty.stats(), None);
+    }
+    
+    #[cfg(feature = "std")]
+    #[test]
+    fn median_and_percentile() {
+        let odd = DataBuffer::from_parts("odd", vec![9, 1, 5], false);
+        assert_eq!(odd.median(), Some(5.0));
+        assert_eq!(odd.data, vec![9, 1, 5]);  // Sorted a copy, not the buffer
+        
+        let even = DataBuffer::from_parts("even", vec![4, 1, 3, 2], false);
+        assert_eq!(even.median(), Some(2.5));
+        assert_eq!(even.percentile(0.0), Some(1.0));
+        assert_eq!(even.percentile(50.0), Some(2.5));
+        assert_eq!(even.percentile(100.0), Some(4.0));
+        assert_eq!(even.percentile(100.5), None);
+        assert_eq!(even.percentile(-1.0), None);
+        
+        let empty: DataBuffer = DataBuffer::from_parts("empty", Vec::new(), false);
+        assert_eq!(empty.median(), None);
+        assert_eq!(empty.percentile(50.0), None);
+    }
+    
+    #[test]
+    fn fill_with_squares_and_state() {
+        let mut buffer: DataBuffer = DataBuffer::new_quiet("squares", 5);
+        buffer.fill_with(|i| (i * i) as i32);
+        assert_eq!(buffer.data, vec![0, 1, 4, 9, 16]);
+        
+        let mut calls = 0;
+        buffer.fill_with(|_| {
+            calls += 1;
+            calls * 10
+        });
+        assert_eq!(buffer.data, vec![10, 20, 30, 40, 50]);
+        assert_eq!(calls, 5);
+    }
+    
+    #[test]
+    fn map_and_filter_leave_original() {
+        let buffer = DataBuffer::from_parts("src", vec![-2, -1, 0, 3, 4], false);
+        let doubled = buffer.map(|x| x * 2);
+        assert_eq!(doubled.data, vec![-4, -2, 0, 6, 8]);
+        assert_eq!(doubled.name(), "src_mapped");
+        
+        let positive = buffer.filter(|&x| x > 0);
+        assert_eq!(positive.data, vec![3, 4]);
+        assert_eq!(positive.name(), "src_filtered");
+        
+        assert_eq!(buffer.data, vec![-2, -1, 0, 3, 4]);
+        assert_eq!(buffer.count_where(|&x| x > 0), 2);
+    }
+    
+    #[test]
+    fn vec_conversions_do_not_reallocate() {
+        let data = vec![1, 2, 3, 4];
+        let ptr = data.as_ptr();
+        let buffer = DataBuffer::from(data);
+        assert_eq!(buffer.name(), "buffer_4");
+        assert_eq!(buffer.data.as_ptr(), ptr);
+        
+        let back: Vec<i32> = buffer.into();
+        assert_eq!(back.as_ptr(), ptr);
+        assert_eq!(back, vec![1, 2, 3, 4]);
+    }
+    
+    #[test]
+    fn try_from_slice_copies() {
+        let empty: &[i32] = &[];
+        match DataBuffer::try_from(empty) {
+            Err(BufferError::EmptyBuffer) => {}
+            other => panic!("expected EmptyBuffer, got {:?}", other),
+        }
+        
+        let source = [5, 6, 7];
+        let copy = DataBuffer::try_from(&source[..]).unwrap();
+        assert_eq!(copy.data, source);
+        assert_ne!(copy.data.as_ptr(), source.as_ptr());
+        assert_eq!(copy.name(), "slice_copy_3");
+    }
+    
+    #[test]
+    fn split_at_cases() {
+        let buffer = DataBuffer::from_parts("s", vec![1, 2, 3, 4], false);
+        let (left, right) = buffer.split_at(1).unwrap();
+        assert_eq!((left.name(), right.name()), ("s_left", "s_right"));
+        assert_eq!(left.data, vec![1]);
+        assert_eq!(right.data, vec![2, 3, 4]);
+        
+        let (empty, all) = right.split_at(0).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(all.data, vec![2, 3, 4]);
+        
+        let (all, empty) = all.split_at(3).unwrap();
+        assert_eq!((all.len(), empty.len()), (3, 0));
+        
+        match all.split_at(4) {
+            Err(BufferError::OutOfBounds { index: 4, len: 3 }) => {}
+            other => panic!("expected OutOfBounds, got {:?}", other.map(|_| ())),
+        }
+    }
+    
+    #[test]
+    fn append_combines_contents() {
+        let mut target = DataBuffer::from_parts("target", vec![1, 2], false);
+        let other = DataBuffer::from_parts("other", vec![3, 4, 5], false);
+        target.append(other);  // `other` is moved in, so it cannot be used after this line
+        assert_eq!(target.len(), 5);
+        assert_eq!(target.data, vec![1, 2, 3, 4, 5]);
+        assert_eq!(target.name(), "target");
+    }
+    
+    #[test]
+    fn equality_compares_data_only() {
+        let a = DataBuffer::from_parts("a", vec![1, 2, 3], false);
+        let b = DataBuffer::from_parts("b", vec![1, 2, 3], false);
+        let c = DataBuffer::from_parts("c", vec![1, 2, 4], false);
+        let d = DataBuffer::from_parts("d", vec![1, 2], false);
+        assert_eq!(a, b);
+        assert!(a.approx_eq(&b));
+        assert_ne!(a, c);
+        assert!(!a.approx_eq(&c));
+        assert_ne!(a, d);
+    }
+    
+    #[cfg(feature = "std")]
+    #[test]
+    fn fill_parallel_matches_sequential() {
+        let mut expected: DataBuffer = DataBuffer::new_quiet("seq", 37);
+        expected.fill_with_values(-10);
+        for &threads in &[0, 1, 2, 3, 8, 37, 100] {
+            let mut parallel: DataBuffer = DataBuffer::new_quiet("par", 37);
+            parallel.fill_parallel(-10, threads);
+            assert_eq!(parallel, expected, "threads = {}", threads);
+        }
+    }
+    
+    // `allocated_bytes` only ever grows, so tests on other threads cannot shrink the delta
+    #[cfg(feature = "tracking-alloc")]
+    #[test]
+    fn allocator_counts_buffer_bytes() {
+        const SIZE: usize = 1000;
+        let before = allocated_bytes();
+        let buffer: DataBuffer<i32> = DataBuffer::new_quiet("tracked", SIZE);
+        assert!(allocated_bytes() - before >= SIZE * 4);
+        drop(buffer);
+    }
+    
+    #[test]
+    fn reverse_sort_dedup() {
+        let mut buffer = DataBuffer::from_parts("r", vec![1, 2, 3], false);
+        buffer.reverse();
+        assert_eq!(buffer.data, vec![3, 2, 1]);
+        
+        let mut buffer = DataBuffer::from_parts("s", vec![3, 1, 2], false);
+        buffer.sort();
+        assert_eq!(buffer.data, vec![1, 2, 3]);
+        
+        let mut buffer = DataBuffer::from_parts("d", vec![1, 1, 2, 2, 2, 3, 1], false);
+        buffer.dedup();
+        assert_eq!(buffer.data, vec![1, 2, 3, 1]);  // Only consecutive runs collapse
+    }
+    
+    #[test]
+    fn binary_search_cases() {
+        let buffer = DataBuffer::from_parts("sorted", vec![1, 3, 5, 7], false);
+        assert_eq!(buffer.binary_search(5), Ok(2));
+        assert_eq!(buffer.binary_search(4), Err(2));
+        assert_eq!(buffer.binary_search(0), Err(0));
+        assert_eq!(buffer.binary_search(8), Err(4));
+        
+        let empty: DataBuffer = DataBuffer::from_parts("empty", Vec::new(), false);
+        assert_eq!(empty.binary_search(1), Err(0));
+    }
+    
+    #[test]
+    fn circular_buffer_keeps_latest() {
+        let mut ring = CircularBuffer::new(3);
+        assert!(ring.is_empty());
+        for value in 1..=5 {
+            ring.push(value);
+        }
+        assert_eq!(ring.len(), 3);
+        assert_eq!(ring.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+        
+        ring.push(6);
+        ring.push(7);  // Head wraps back to index 0
+        assert_eq!(ring.iter().copied().collect::<Vec<_>>(), vec![5, 6, 7]);
+        assert_eq!(ring.data.capacity(), 3);
+    }
+    
+    #[test]
+    fn uninit_fill_matches_two_pass_fill() {
+        for &(size, start) in &[(1, 0), (7, -3), (1000, 42)] {
+            let mut expected: DataBuffer = DataBuffer::new_quiet("two_pass", size);
+            expected.fill_with_values(start);
+            let mut single = DataBuffer::new_uninit_then_fill("one_pass", size, start);
+            single.verbose = false;
+            assert_eq!(single, expected);
+            assert_eq!(single.capacity(), size);
+        }
+    }
+    
+    #[test]
+    fn drops_run_in_reverse_order() {
+        use alloc::rc::Rc;
+        use core::cell::RefCell;
+        
+        // Records the wrapped buffer's name as it is dropped
+        struct Recorded(DataBuffer, Rc<RefCell<Vec<String>>>);
+        impl Drop for Recorded {
+            fn drop(&mut self) {
+                self.1.borrow_mut().push(self.0.name().to_string());
+            }
+        }
+        
+        let log = Rc::new(RefCell::new(Vec::new()));
+        {
+            let _a = Recorded(DataBuffer::new_quiet("A", 1), Rc::clone(&log));
+            let _b = Recorded(DataBuffer::new_quiet("B", 1), Rc::clone(&log));
+            {
+                let _inner = Recorded(DataBuffer::new_quiet("Inner", 1), Rc::clone(&log));
+            }
+            let _c = Recorded(DataBuffer::new_quiet("C", 1), Rc::clone(&log));
+        }
+        assert_eq!(*log.borrow(), vec!["Inner", "C", "B", "A"]);
+    }
+    
+    #[test]
+    fn info_string_has_name_and_count() {
+        let buffer: DataBuffer = DataBuffer::new_quiet("described", 12);
+        let info = buffer.info_string();
+        assert!(info.contains("Buffer 'described' has 12 elements"));
+        assert!(info.contains(&format!("{:p}", buffer.data.as_ptr())));
+    }
+    
+    #[test]
+    fn builder_cases() {
+        let full = DataBufferBuilder::new().name("built").size(3).fill_start(7).build().unwrap();
+        assert_eq!(full.name(), "built");
+        assert_eq!(full.data, vec![7, 8, 9]);
+        
+        let unnamed = DataBufferBuilder::new().size(2).build().unwrap();
+        assert_eq!(unnamed.name(), "unnamed");
+        assert_eq!(unnamed.data, vec![0, 0]);
+        
+        match DataBufferBuilder::new().name("zero").build() {
+            Err(BufferError::EmptyBuffer) => {}
+            other => panic!("expected EmptyBuffer, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn extend_paths() {
+        let mut buffer = DataBuffer::from_parts("grow", vec![1, 2, 3], false);
+        let source = [4, 5, 6, 7];
+        buffer.extend_from_slice(&source);
+        assert_eq!(buffer.len(), 7);
+        assert_eq!(buffer.data, vec![1, 2, 3, 4, 5, 6, 7]);
+        
+        buffer.extend(8..10);
+        buffer.extend([10]);
+        assert_eq!(buffer.data[7..], [8, 9, 10]);
+        assert_eq!(source, [4, 5, 6, 7]);  // Only borrowed
+    }
+    
+    #[test]
+    fn insert_and_remove_positions() {
+        let mut buffer = DataBuffer::from_parts("shift", vec![2, 4], false);
+        buffer.insert(0, 1).unwrap();
+        buffer.insert(2, 3).unwrap();
+        buffer.insert(4, 5).unwrap();  // index == len appends
+        assert_eq!(buffer.data, vec![1, 2, 3, 4, 5]);
+        
+        match buffer.insert(6, 0) {
+            Err(BufferError::OutOfBounds { index: 6, len: 5 }) => {}
+            other => panic!("expected OutOfBounds, got {:?}", other),
+        }
+        
+        assert_eq!(buffer.remove(4).unwrap(), 5);
+        assert_eq!(buffer.remove(0).unwrap(), 1);
+        assert_eq!(buffer.remove(1).unwrap(), 3);
+        assert_eq!(buffer.data, vec![2, 4]);
+        match buffer.remove(2) {
+            Err(BufferError::OutOfBounds { index: 2, len: 2 }) => {}
+            other => panic!("expected OutOfBounds, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn retain_and_drain() {
+        let mut buffer = DataBuffer::from_parts("keep", vec![-1, 2, -3, 4], false);
+        buffer.retain(|&x| x > 0);
+        assert_eq!(buffer.data, vec![2, 4]);
+        buffer.retain(|_| false);
+        assert!(buffer.is_empty());
+        
+        let mut buffer = DataBuffer::from_parts("drain", vec![1, 2, 3, 4, 5], false);
+        assert_eq!(buffer.drain_range(1..3).unwrap(), vec![2, 3]);
+        assert_eq!(buffer.data, vec![1, 4, 5]);
+        match buffer.drain_range(2..4) {
+            Err(BufferError::InvalidRange { start: 2, end: 4, len: 3 }) => {}
+            other => panic!("expected InvalidRange, got {:?}", other),
+        }
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = buffer.drain_range(2..1);
+        assert!(reversed.is_err());
+        assert_eq!(buffer.data, vec![1, 4, 5]);
+    }
+    
+    #[test]
+    fn histogram_cases() {
+        let buffer = DataBuffer::from_parts("h", (0..10).collect(), false);
+        assert_eq!(buffer.histogram(5).unwrap(), vec![2, 2, 2, 2, 2]);
+        assert_eq!(buffer.histogram(3).unwrap(), vec![4, 3, 3]);
+        
+        let extremes = DataBuffer::from_parts("x", vec![i32::MIN, 0, i32::MAX], false);
+        assert_eq!(extremes.histogram(2).unwrap(), vec![1, 2]);  // Max lands in the last bin
+        
+        let single = DataBuffer::from_parts("one", vec![7, 7, 7], false);
+        assert_eq!(single.histogram(4).unwrap(), vec![3, 0, 0, 0]);
+        
+        match buffer.histogram(0) {
+            Err(BufferError::InvalidArgument(_)) => {}
+            other => panic!("expected InvalidArgument, got {:?}", other),
+        }
+        let empty: DataBuffer = DataBuffer::from_parts("empty", Vec::new(), false);
+        match empty.histogram(3) {
+            Err(BufferError::EmptyBuffer) => {}
+            other => panic!("expected EmptyBuffer, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn prefix_sum_running_totals() {
+        let buffer = DataBuffer::from_parts("p", vec![1, 2, 3, 4], false);
+        let prefix = buffer.prefix_sum();
+        assert_eq!(prefix.data, vec![1, 3, 6, 10]);
+        assert_eq!(prefix.name(), "p_prefix");
+        
+        let big = DataBuffer::from_parts("big", vec![i32::MAX, i32::MAX], false);
+        assert_eq!(big.prefix_sum().data[1], 2 * i32::MAX as i64);
+        
+        let empty: DataBuffer = DataBuffer::from_parts("empty", Vec::new(), false);
+        assert!(empty.prefix_sum().is_empty());
+    }
+    
+    #[test]
+    fn dot_product_and_mismatch() {
+        let a = DataBuffer::from_parts("a", vec![1, 2, 3], false);
+        let b = DataBuffer::from_parts("b", vec![4, -5, 6], false);
+        assert_eq!(a.dot(&b).unwrap(), 4 - 10 + 18);
+        
+        let wide = DataBuffer::from_parts("w", vec![i32::MAX, i32::MAX], false);
+        assert_eq!(wide.dot(&wide).unwrap(), 2 * (i32::MAX as i64) * (i32::MAX as i64));
+        
+        let short = DataBuffer::from_parts("s", vec![1], false);
+        match a.dot(&short) {
+            Err(BufferError::LengthMismatch { left: 3, right: 1 }) => {}
+            other => panic!("expected LengthMismatch, got {:?}", other),
+        }
+    }
+    
+    #[cfg(feature = "std")]
+    #[test]
+    fn norm_of_three_four() {
+        let buffer = DataBuffer::from_parts("v", vec![3, 4], false);
+        assert_eq!(buffer.norm(), 5.0);
+    }
+    
+    #[test]
+    fn hex_dump_is_little_endian() {
+        let buffer = DataBuffer::from_parts("hex", vec![0x0102_0304], false);
+        let dump = buffer.hex_dump();
+        assert!(dump.starts_with("00000000  04 03 02 01 "));
+        assert!(dump.ends_with("  |....|\n"));
+        assert_eq!(dump.lines().count(), 1);
+        
+        let ascii = DataBuffer::from_parts("ascii", vec![0x6463_6261; 5], false);
+        let dump = ascii.hex_dump();
+        assert_eq!(dump.lines().count(), 2);  // 20 bytes: one full row and a partial one
+        assert!(dump.contains("|abcdabcdabcdabcd|"));
+        assert!(dump.starts_with("00000000  61 62 63 64 61 62 63 64  61"));
+        assert!(dump.contains("\n00000010  61 62 63 64 "));
+    }
+    
+    #[test]
+    fn csv_round_trip() {
+        let buffer = DataBuffer::from_parts("csv", vec![-1, 0, 42], false);
+        let csv = buffer.to_csv();
+        assert_eq!(csv, "-1\n0\n42\n");
+        assert_eq!(DataBuffer::from_csv(&csv).unwrap(), buffer);
+        
+        let messy = DataBuffer::from_csv(" 5 \n\n6\t\n\n\n").unwrap();
+        assert_eq!(messy.data, vec![5, 6]);
+    }
+    
+    #[test]
+    fn csv_rejects_malformed_token() {
+        match DataBuffer::from_csv("1\nabc\n3") {
+            Err(BufferError::Parse { line: 2, ref token }) if token == "abc" => {}
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        assert!(DataBuffer::from_csv("99999999999").is_err());  // Out of i32 range
+    }
+    
+    #[cfg(feature = "std")]
+    #[test]
+    fn file_round_trip() {
+        let path = std::env::temp_dir().join(format!("buffer_test_{}.bin", std::process::id()));
+        let buffer = DataBuffer::from_parts("saved", vec![3, -1, 4, 1, -5], false);
+        buffer.save_to_file(&path).unwrap();
+        let loaded = DataBuffer::load_from_file(&path);
+        fs::remove_file(&path).unwrap();
+        
+        let mut loaded = loaded.unwrap();
+        loaded.verbose = false;
+        assert_eq!(loaded, buffer);
+        assert!(loaded.name().starts_with("buffer_test_"));  // Named after the file stem
+    }
+    
+    #[cfg(feature = "std")]
+    #[test]
+    fn loading_missing_file_is_io_error() {
+        let path = std::env::temp_dir().join("buffer_test_does_not_exist.bin");
+        match DataBuffer::load_from_file(&path) {
+            Err(BufferError::Io(ref e)) => assert_eq!(e.kind(), io::ErrorKind::NotFound),
+            other => panic!("expected an I/O error, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn weak_upgrade_fails_after_drop() {
+        use alloc::rc::{Rc, Weak};
+        use core::cell::RefCell;
+        
+        let parent = Rc::new(RefCell::new(DataBuffer::<i32>::new_quiet("parent", 2)));
+        let child: Weak<RefCell<DataBuffer>> = Rc::downgrade(&parent);
+        assert_eq!(Rc::strong_count(&parent), 1);
+        assert_eq!(Rc::weak_count(&parent), 1);
+        assert_eq!(child.upgrade().map(|p| p.borrow().len()), Some(2));
+        
+        drop(parent);
+        assert!(child.upgrade().is_none());
+        assert_eq!(child.strong_count(), 0);
+    }
+    
+    #[test]
+    fn manually_drop_waits_for_explicit_drop() {
+        use alloc::rc::Rc;
+        use core::cell::RefCell;
+        use core::mem::ManuallyDrop;
+        
+        struct Recorded(DataBuffer, Rc<RefCell<Vec<String>>>);
+        impl Drop for Recorded {
+            fn drop(&mut self) {
+                self.1.borrow_mut().push(self.0.name().to_string());
+            }
+        }
+        
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut kept;
+        {
+            kept = ManuallyDrop::new(Recorded(DataBuffer::new_quiet("kept", 1), Rc::clone(&log)));
+            let _auto = Recorded(DataBuffer::new_quiet("auto", 1), Rc::clone(&log));
+        }
+        assert_eq!(*log.borrow(), vec!["auto"]);  // Scope exit skipped `kept`
+        
+        // SAFETY: `kept` is dropped exactly once and never used afterwards
+        unsafe { ManuallyDrop::drop(&mut kept) };
+        assert_eq!(*log.borrow(), vec!["auto", "kept"]);
+    }
+    
+    #[test]
+    fn concat_keeps_order() {
+        let parts = vec![
+            DataBuffer::from_parts("a", vec![1, 2], false),
+            DataBuffer::from_parts("b", vec![3], false),
+            DataBuffer::from_parts("c", vec![4, 5, 6], false),
+        ];
+        let mut combined = concat(parts);
+        combined.verbose = false;
+        assert_eq!(combined.name(), "concat");
+        assert_eq!(combined.len(), 6);
+        assert_eq!(combined.data, vec![1, 2, 3, 4, 5, 6]);
+        
+        let mut empty = concat(Vec::<DataBuffer>::new());
+        empty.verbose = false;
+        assert!(empty.is_empty());
+    }
+    
+    #[test]
+    fn swap_and_swap_remove() {
+        let mut buffer = DataBuffer::from_parts("sw", vec![1, 2, 3, 4], false);
+        buffer.swap(0, 3).unwrap();
+        assert_eq!(buffer.data, vec![4, 2, 3, 1]);
+        buffer.swap(1, 1).unwrap();
+        
+        match buffer.swap(4, 0) {
+            Err(BufferError::OutOfBounds { index: 4, len: 4 }) => {}
+            other => panic!("expected OutOfBounds on i, got {:?}", other),
+        }
+        match buffer.swap(0, 9) {
+            Err(BufferError::OutOfBounds { index: 9, len: 4 }) => {}
+            other => panic!("expected OutOfBounds on j, got {:?}", other),
+        }
+        assert_eq!(buffer.data, vec![4, 2, 3, 1]);
+        
+        assert_eq!(buffer.swap_remove(0).unwrap(), 4);
+        assert_eq!(buffer.data, vec![1, 2, 3]);  // Last element filled the hole
+        assert!(buffer.swap_remove(3).is_err());
+    }
+    
+    #[test]
+    fn fill_range_cases() {
+        let mut buffer = DataBuffer::from_parts("fr", vec![0; 5], false);
+        buffer.fill_range(0..5, 1).unwrap();
+        assert_eq!(buffer.data, vec![1; 5]);
+        buffer.fill_range(1..3, 9).unwrap();
+        assert_eq!(buffer.data, vec![1, 9, 9, 1, 1]);
+        buffer.fill_range(2..2, 7).unwrap();
+        assert_eq!(buffer.data, vec![1, 9, 9, 1, 1]);
+        
+        match buffer.fill_range(3..6, 0) {
+            Err(BufferError::InvalidRange { start: 3, end: 6, len: 5 }) => {}
+            other => panic!("expected InvalidRange, got {:?}", other),
+        }
+        assert_eq!(buffer.data, vec![1, 9, 9, 1, 1]);
+    }
+    
+    #[test]
+    fn rotations() {
+        let mut buffer = DataBuffer::from_parts("rot", vec![1, 2, 3, 4, 5], false);
+        buffer.rotate_left(2);
+        assert_eq!(buffer.data, vec![3, 4, 5, 1, 2]);
+        buffer.rotate_right(2);
+        assert_eq!(buffer.data, vec![1, 2, 3, 4, 5]);
+        buffer.rotate_right(2);
+        assert_eq!(buffer.data, vec![4, 5, 1, 2, 3]);
+        
+        buffer.rotate_left(5);
+        buffer.rotate_right(10);
+        assert_eq!(buffer.data, vec![4, 5, 1, 2, 3]);
+        
+        let mut empty: DataBuffer = DataBuffer::from_parts("empty", Vec::new(), false);
+        empty.rotate_left(3);  // No modulo-by-zero panic
+        assert!(empty.is_empty());
+    }
+    
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_set_dedups_by_content() {
+        use std::collections::HashSet;
+        
+        let mut set = HashSet::new();
+        set.insert(DataBuffer::from_parts("a", vec![1, 2], false));
+        set.insert(DataBuffer::from_parts("b", vec![1, 2], false));
+        assert_eq!(set.len(), 1);
+        set.insert(DataBuffer::from_parts("c", vec![2, 1], false));
+        assert_eq!(set.len(), 2);
+    }
+    
+    #[test]
+    fn buffers_sort_lexicographically() {
+        let mut buffers = [
+            DataBuffer::from_parts("long", vec![1, 2, 3], false),
+            DataBuffer::from_parts("big", vec![2], false),
+            DataBuffer::from_parts("short", vec![1, 2], false),
+        ];
+        buffers.sort();
+        let names: Vec<&str> = buffers.iter().map(|b| b.name()).collect();
+        assert_eq!(names, vec!["short", "long", "big"]);  // A prefix sorts first
+        assert!(buffers[0] < buffers[1]);
+    }
+    
+    #[test]
+    fn chunk_views_and_owned_chunks() {
+        let buffer = DataBuffer::from_parts("ch", vec![1, 2, 3, 4, 5], false);
+        let views: Vec<&[i32]> = buffer.chunks(2).unwrap().collect();
+        assert_eq!(views, vec![&[1, 2][..], &[3, 4], &[5]]);
+        assert!(buffer.chunks(0).is_err());
+        
+        let even = DataBuffer::from_parts("even", vec![1, 2, 3, 4], false).into_chunks(2).unwrap();
+        assert_eq!(even.len(), 2);
+        assert_eq!(even[1].data, vec![3, 4]);
+        
+        // `buffer` is moved into into_chunks and dropped there
+        let owned = buffer.into_chunks(2).unwrap();
+        let names: Vec<&str> = owned.iter().map(|b| b.name()).collect();
+        assert_eq!(names, vec!["ch_chunk0", "ch_chunk1", "ch_chunk2"]);
+        assert_eq!(owned[2].data, vec![5]);
+        
+        match DataBuffer::from_parts("z", vec![1], false).into_chunks(0) {
+            Err(BufferError::InvalidArgument(_)) => {}
+            other => panic!("expected InvalidArgument, got {:?}", other.map(|_| ())),
+        }
+    }
+    
+    #[test]
+    fn truncate_and_resize() {
+        let mut buffer = DataBuffer::from_parts("tr", vec![1, 2, 3, 4], false);
+        let capacity = buffer.capacity();
+        buffer.truncate(2);
+        assert_eq!(buffer.data, vec![1, 2]);
+        assert_eq!(buffer.capacity(), capacity);  // Shrinking keeps the allocation
+        buffer.truncate(10);
+        assert_eq!(buffer.len(), 2);
+        
+        buffer.resize(5, 7);
+        assert_eq!(buffer.data, vec![1, 2, 7, 7, 7]);
+        buffer.resize(5, 0);
+        assert_eq!(buffer.data, vec![1, 2, 7, 7, 7]);
+        buffer.resize(1, 0);
+        assert_eq!(buffer.data, vec![1]);
+    }
+    
+    #[test]
+    fn contains_and_position() {
+        let buffer = DataBuffer::from_parts("find", vec![4, 8, 15, 8], false);
+        assert!(buffer.contains(15));
+        assert_eq!(buffer.position(15), Some(2));
+        assert!(!buffer.contains(16));
+        assert_eq!(buffer.position(16), None);
+        assert_eq!(buffer.position(8), Some(1));
+    }
+    
+    #[test]
+    fn count_where_cases() {
+        let buffer = DataBuffer::from_parts("count", vec![-2, -1, 0, 1, 2, 3], false);
+        assert_eq!(buffer.count_where(|&x| x % 2 == 0), 3);
+        assert_eq!(buffer.count_where(|_| true), 6);
+        assert_eq!(buffer.count_where(|&x| x > 100), 0);
+        assert_eq!(process_buffer(&buffer), 3);
+    }
+    
+    #[test]
+    fn clear_keeps_capacity() {
+        let mut buffer: DataBuffer = DataBuffer::new_quiet("clr", 8);
+        buffer.fill_with_values(1);
+        let capacity = buffer.capacity();
+        assert!(!buffer.is_empty());
+        
+        buffer.clear();
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.len(), 0);
+        assert_eq!(buffer.capacity(), capacity);
+        assert_eq!(buffer.stats(), None);
+        assert_eq!(buffer.into_sum(), 0);
+    }
+    
+    #[test]
+    fn moving_average_cases() {
+        let buffer = DataBuffer::from_parts("sig", vec![1, 2, 3, 4, 6], false);
+        assert_eq!(buffer.moving_average(1).unwrap().data, vec![1.0, 2.0, 3.0, 4.0, 6.0]);
+        assert_eq!(buffer.moving_average(5).unwrap().data, vec![3.2]);
+        let smoothed = buffer.moving_average(2).unwrap();
+        assert_eq!(smoothed.data, vec![1.5, 2.5, 3.5, 5.0]);
+        assert_eq!(smoothed.name(), "sig_smoothed");
+        
+        assert!(buffer.moving_average(0).is_err());
+        assert!(buffer.moving_average(6).is_err());
+    }
+    
+    #[test]
+    fn rle_encode_and_round_trip() {
+        let buffer = DataBuffer::from_parts("rle", vec![5, 5, 5, 2, 2], false);
+        let runs = buffer.rle_encode();
+        assert_eq!(runs, vec![(5, 3), (2, 2)]);
+        let mut decoded = DataBuffer::rle_decode(&runs).unwrap();
+        decoded.verbose = false;
+        assert_eq!(decoded, buffer);
+        
+        let varied = DataBuffer::from_parts("v", vec![1, 2, 2, 1, 1, 1, -4], false);
+        let mut decoded = DataBuffer::rle_decode(&varied.rle_encode()).unwrap();
+        decoded.verbose = false;
+        assert_eq!(decoded, varied);
+        
+        let empty: DataBuffer = DataBuffer::from_parts("empty", Vec::new(), false);
+        assert!(empty.rle_encode().is_empty());
+        assert!(DataBuffer::rle_decode(&[(1, usize::MAX), (2, 1)]).is_err());
+    }
+    
+    #[test]
+    fn shrink_to_fit_after_truncate() {
+        let mut buffer: DataBuffer = DataBuffer::new_quiet("shrink", 4);
+        for value in 0..100 {
+            buffer.push(value);
+        }
+        buffer.truncate(10);
+        assert!(buffer.capacity() > 10);
+        buffer.shrink_to_fit();
+        assert_eq!(buffer.capacity(), buffer.len());
+        assert_eq!(buffer.heap_bytes(), 10 * 4);
+    }
+    
+    #[test]
+    fn scaled_iter_matches_map() {
+        let buffer = DataBuffer::from_parts("lazy", vec![-2, 0, 5, i32::MAX], false);
+        let lazy: Vec<i32> = buffer.scaled_iter(3).collect();
+        let eager = buffer.map(|x| x.wrapping_mul(3));
+        assert_eq!(lazy, eager.data);
+        assert_eq!(buffer.data, vec![-2, 0, 5, i32::MAX]);
+    }
+    
+    #[cfg(feature = "std")]
+    #[test]
+    fn select_demo_parses_arguments() {
+        assert_eq!(select_demo(None), DemoSelection::All);
+        assert_eq!(select_demo(Some("all")), DemoSelection::All);
+        assert_eq!(select_demo(Some("3")), DemoSelection::One(3));
+        assert_eq!(select_demo(Some("demo3")), DemoSelection::One(3));
+        assert_eq!(select_demo(Some("1")), DemoSelection::One(1));
+        assert_eq!(select_demo(Some(&DEMOS.len().to_string())), DemoSelection::One(DEMOS.len()));
+        for unknown in &["0", "demo0", "999", "demo", "three", "-1", ""] {
+            assert_eq!(select_demo(Some(unknown)), DemoSelection::Unknown(unknown.to_string()));
+        }
+    }
+    
+    #[cfg(feature = "std")]
+    #[test]
+    fn demo_errors_propagate() {
+        fn run_all(numbers: &[usize]) -> Result<(), Box<dyn Error>> {
+            for &number in numbers {
+                run_demo(number)?;
+            }
+            Ok(())
+        }
+        
+        for &number in &[0, DEMOS.len() + 1] {
+            match run_demo(number) {
+                Err(BufferError::InvalidArgument(_)) => {}
+                other => panic!("demo {} should be rejected, got {:?}", number, other),
+            }
+        }
+        let err = run_all(&[0]).unwrap_err();
+        assert!(err.to_string().contains("no demo with that number"));
+    }
+    
+    #[test]
+    fn into_reduce_reducers() {
+        let values = vec![3, -1, 4, 1, 5];
+        let sum = DataBuffer::from_parts("sum", values.clone(), false).into_reduce(0, |acc, x| acc + x as i64);
+        assert_eq!(sum, 12);
+        let product = DataBuffer::from_parts("product", values.clone(), false).into_reduce(1, |acc, x| acc * x as i64);
+        assert_eq!(product, -60);
+        let max = DataBuffer::from_parts("max", values, false).into_reduce(i64::MIN, |acc, x| acc.max(x as i64));
+        assert_eq!(max, 5);
+        
+        let wide = DataBuffer::from_parts("wide", vec![i32::MAX; 3], false);
+        assert_eq!(wide.into_reduce(0, |acc, x| acc + x as i64), 3 * i32::MAX as i64);
+    }
+    
+    #[test]
+    fn metadata_set_get_overwrite() {
+        let mut buffer: DataBuffer = DataBuffer::new_quiet("tagged", 2);
+        assert_eq!(buffer.get_meta("owner"), None);
+        buffer.set_meta("owner", "alice");
+        let key = String::from("unit");
+        buffer.set_meta(key, String::from("ms"));  // Both strings move into the map
+        assert_eq!(buffer.get_meta("owner"), Some("alice"));
+        assert_eq!(buffer.get_meta("unit"), Some("ms"));
+        
+        buffer.set_meta("owner", "bob");
+        assert_eq!(buffer.get_meta("owner"), Some("bob"));
+        assert_eq!(buffer.metadata.len(), 2);
+        
+        let copy = buffer.clone();
+        assert_eq!(copy.get_meta("owner"), Some("bob"));
+        let mut untagged: DataBuffer = DataBuffer::new_quiet("plain", 2);
+        untagged.set_meta("owner", "carol");
+        assert_eq!(untagged, buffer);  // Tags do not affect equality
+    }
+    
+    #[test]
+    fn pool_reuse_starts_fresh() {
+        let mut pool: BufferPool = BufferPool::new();
+        let mut buffer = pool.acquire("first", 4).unwrap();
+        buffer.verbose = false;
+        buffer.set_meta("owner", "first");
+        buffer.fill_with_values(9);
+        let mut copy = buffer.clone();
+        copy.verbose = false;
+        assert_eq!(copy.generation, 1);
+        pool.release(copy);
+        
+        let reused = pool.acquire("second", 4).unwrap();
+        assert_eq!(reused.name(), "second");
+        assert_eq!(reused.get_meta("owner"), None);
+        assert_eq!(reused.generation, 0);
+        assert_eq!(reused.data, vec![0; 4]);
+        assert!(pool.acquire("zero", 0).is_err());
+    }
+    
+    #[cfg(feature = "std")]
+    #[test]
+    fn layout_fields_cover_struct_size() {
+        let probe: DataBuffer = DataBuffer::from_parts("layout", Vec::new(), false);
+        let fields: usize = field_sizes(&probe).iter().map(|&(_, size)| size).sum();
+        let align = core::mem::align_of::<DataBuffer>();
+        // Fields are reordered to pack tightly, so only the tail needs padding
+        assert_eq!(core::mem::size_of::<DataBuffer>(), fields.div_ceil(align) * align);
+    }
+    
+    #[test]
+    fn small_buffer_inline_then_spill() {
+        let mut small: SmallBuffer<i32, 4> = SmallBuffer::new();
+        assert!(small.is_empty());
+        for value in 1..=4 {
+            small.push(value);
+            assert!(small.is_inline());
+        }
+        assert_eq!(small.as_slice(), &[1, 2, 3, 4]);
+        
+        small.push(5);
+        assert!(!small.is_inline());
+        assert_eq!(small.len(), 5);
+        assert_eq!(small.as_slice(), &[1, 2, 3, 4, 5]);
+        small.push(6);
+        assert_eq!(small.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    }
+    
+    #[test]
+    fn fixed_buffer_fill_and_sum() {
+        let mut fixed = FixedBuffer::<4>::new();
+        assert_eq!(fixed.sum(), 0);
+        fixed.fill_with_values(1);
+        assert_eq!(fixed.data, [1, 2, 3, 4]);
+        assert_eq!(fixed.sum(), 10);
+        assert_eq!(core::mem::size_of::<FixedBuffer<4>>(), 4 * 4);
+        
+        let mut big = FixedBuffer::<3>::new();
+        big.fill_with_values(i32::MAX - 2);
+        assert_eq!(big.sum(), 3 * i32::MAX as i64 - 3);
+    }
+    
+    // Only in-range indices: anything else is undefined behavior, not a failure
+    // to assert on. In release builds these skip the compare-and-branch that
+    // `get`/`set` do per access, which only shows up in tight loops.
+    #[test]
+    fn unchecked_access_in_range() {
+        let mut buffer = DataBuffer::from_parts("raw", vec![10, 20, 30], false);
+        for i in 0..buffer.len() {
+            // SAFETY: `i < buffer.len()` by the loop bound
+            let value = unsafe { buffer.get_unchecked(i) };
+            assert_eq!(value, buffer.get(i).unwrap());
+            // SAFETY: same index, still in range
+            unsafe { buffer.set_unchecked(i, value + 1) };
+        }
+        assert_eq!(buffer.data, vec![11, 21, 31]);
+    }
+    
+    // The same three paths DEMO 55 times, so the timings compare like with like
+    #[test]
+    fn fill_paths_agree() {
+        for &size in &[1, 2, 1_000, 100_000] {
+            let mut zeroed: DataBuffer = DataBuffer::new_quiet("zeroed", size);
+            zeroed.fill_with_values(0);
+            let mut reserved: DataBuffer = DataBuffer::from_parts("reserved", Vec::with_capacity(size), false);
+            reserved.extend((0..size).map(|i| i as i32));
+            let mut single = DataBuffer::new_uninit_then_fill("single", size, 0);
+            single.verbose = false;
+            
+            assert_eq!(zeroed, reserved, "size {}", size);
+            assert_eq!(reserved, single, "size {}", size);
+        }
+    }
+    
+    /// Deterministic xorshift stream standing in for a property-testing crate
+    fn pseudo_random(seed: u64) -> impl Iterator<Item = u64> {
+        let mut state = seed | 1;
+        core::iter::repeat_with(move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        })
+    }
+    
+    /// Up to 63 arbitrary i32 values drawn from `rng`
+    fn random_values(rng: &mut impl Iterator<Item = u64>) -> Vec<i32> {
+        let len = rng.next().unwrap() % 64;
+        rng.take(len as usize).map(|x| x as i32).collect()
+    }
+    
+    #[test]
+    fn fill_then_wide_sum_matches_closed_form() {
+        let mut rng = pseudo_random(0x5eed);
+        for _ in 0..300 {
+            let size = (rng.next().unwrap() % 2_000 + 1) as usize;
+            // Any start whose last element still fits in i32, extremes included
+            let span = (i32::MAX as i64 - size as i64 + 1) - i32::MIN as i64;
+            let start = (i32::MIN as i64 + (rng.next().unwrap() % span as u64) as i64) as i32;
+            for &start in &[start, i32::MIN, i32::MAX - (size as i32 - 1)] {
+                let mut buffer: DataBuffer = DataBuffer::new_quiet("prop", size);
+                buffer.fill_with_values(start);
+                let n = size as i64;
+                let expected = n * start as i64 + n * (n - 1) / 2;
+                assert_eq!(buffer.wide_sum(), expected, "size {}, start {}", size, start);
+            }
+        }
+    }
+    
+    #[test]
+    fn map_preserves_length() {
+        let mut rng = pseudo_random(0xa11);
+        for _ in 0..300 {
+            let buffer = DataBuffer::from_parts("prop", random_values(&mut rng), false);
+            let factor = rng.next().unwrap() as i32;
+            assert_eq!(buffer.map(|x| x.wrapping_mul(factor)).len(), buffer.len());
+            assert_eq!(buffer.map(|_| 0).len(), buffer.len());
+        }
+    }
+    
+    #[test]
+    fn reverse_twice_is_identity() {
+        let mut rng = pseudo_random(0xbeef);
+        for _ in 0..300 {
+            let values = random_values(&mut rng);
+            let mut buffer = DataBuffer::from_parts("prop", values.clone(), false);
+            buffer.reverse();
+            buffer.reverse();
+            assert_eq!(buffer.data, values);
+        }
+    }
+    
+    #[test]
+    fn from_bytes_regression_seeds() {
+        match DataBuffer::from_bytes_le(&[]) {
+            Err(BufferError::Truncated { expected: 8, actual: 0 }) => {}
+            other => panic!("empty slice: {:?}", other),
+        }
+        match DataBuffer::from_bytes_le(&[0x2a]) {
+            Err(BufferError::Truncated { expected: 8, actual: 1 }) => {}
+            other => panic!("single stray byte: {:?}", other),
+        }
+        match DataBuffer::from_bytes_le(&u64::MAX.to_le_bytes()) {
+            Err(BufferError::TooLarge { .. }) => {}
+            other => panic!("absurd length prefix: {:?}", other),
+        }
+    }
+    
+    // Fuzz-style sweep: whatever the bytes, the parser returns instead of panicking
+    #[test]
+    fn from_bytes_never_panics() {
+        let valid = DataBuffer::from_parts("v", vec![1, -2, 3], false).to_bytes_le();
+        for end in 0..=valid.len() {
+            let _ = DataBuffer::from_bytes_le(&valid[..end]);
+        }
+        let mut rng = pseudo_random(0xf022);
+        for _ in 0..2_000 {
+            let len = (rng.next().unwrap() % 40) as usize;
+            let mut bytes: Vec<u8> = rng.by_ref().take(len).map(|x| x as u8).collect();
+            if bytes.len() >= LEN_PREFIX_BYTES && rng.next().unwrap() & 1 == 0 {
+                // Small declared counts reach the element parsing, not just the size check
+                let count = rng.next().unwrap() % 10;
+                bytes[..LEN_PREFIX_BYTES].copy_from_slice(&count.to_le_bytes());
+            }
+            if let Ok(mut decoded) = DataBuffer::from_bytes_le(&bytes) {
+                decoded.verbose = false;
+                assert!(LEN_PREFIX_BYTES + decoded.len() * 4 <= bytes.len());
+            }
+        }
+    }
+    
+    /// Collects every log record so tests can look for their own buffer's events
+    #[cfg(all(feature = "log", feature = "std"))]
+    struct CaptureLogger(std::sync::Mutex<Vec<String>>);
+    
+    #[cfg(all(feature = "log", feature = "std"))]
+    impl log::Log for CaptureLogger {
+        fn enabled(&self, _: &log::Metadata) -> bool {
+            true
+        }
+        
+        fn log(&self, record: &log::Record) {
+            self.0.lock().unwrap().push(record.args().to_string());
+        }
+        
+        fn flush(&self) {}
+    }
+    
+    #[cfg(all(feature = "log", feature = "std"))]
+    #[test]
+    fn lifecycle_events_reach_the_logger() {
+        static LOGGER: CaptureLogger = CaptureLogger(std::sync::Mutex::new(Vec::new()));
+        log::set_logger(&LOGGER).expect("no other test installs a logger");
+        log::set_max_level(log::LevelFilter::Info);
+        // Other tests log concurrently, so only look at this buffer's records
+        let mine = || -> Vec<String> {
+            let records = LOGGER.0.lock().unwrap();
+            records.iter().filter(|r| r.contains("'logged'")).cloned().collect()
+        };
+        
+        let buffer: DataBuffer = DataBuffer::new("logged", 2);
+        assert!(mine()[0].starts_with("✓ Creating buffer 'logged' with 2 elements"));
+        assert!(!mine().iter().any(|r| r.contains("Dropping")));
+        drop(buffer);
+        assert!(mine().last().unwrap().starts_with("✗ Dropping buffer 'logged'"));
+        
+        let quiet: DataBuffer = DataBuffer::new_quiet("logged", 2);
+        let before = mine().len();
+        drop(quiet);
+        assert_eq!(mine().len(), before);  // Quiet buffers log nothing
+    }
+    
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn num_buffer_for_i64_and_f64() {
+        let mut ints: NumBuffer<i64> = NumBuffer::new(4);
+        ints.fill_with_values(i32::MAX as i64);
+        assert_eq!(ints.sum(), 4 * i32::MAX as i64 + 6);  // Past i32 with no widening
+        assert!(ints.dot(&NumBuffer::new(3)).is_err());
+        
+        // `norm` needs `Float`, so it exists for f64 but not for i64
+        let mut floats: NumBuffer<f64> = NumBuffer::new(2);
+        floats.fill_with_values(3.0);
+        assert_eq!(floats.data, vec![3.0, 4.0]);
+        assert_eq!(floats.norm(), 5.0);
+        assert_eq!(floats.dot(&floats).unwrap(), 25.0);
+    }
+    
+    #[test]
+    fn float_stats_skip_nan() {
+        let mut buffer = DataBuffer::from_parts("f", vec![2.0, f64::NAN, -1.0, 5.0, f64::NAN], false);
+        let stats = buffer.stats_f64().unwrap();
+        assert_eq!(stats.nan_count, 2);
+        assert_eq!(stats.count, 3);
+        assert_eq!((stats.min, stats.max), (-1.0, 5.0));
+        assert_eq!(stats.sum, 6.0);
+        assert_eq!(stats.mean, 2.0);
+        
+        buffer.sort_f64();
+        assert_eq!(buffer.data[..3], [-1.0, 2.0, 5.0]);
+        assert!(buffer.data[3].is_nan() && buffer.data[4].is_nan());  // Positive NaN sorts last
+        
+        let all_nan = DataBuffer::from_parts("nan", vec![f64::NAN], false);
+        assert_eq!(all_nan.stats_f64(), None);
+    }
+    
+    #[test]
+    fn index_read_write_and_slice() {
+        let mut buffer = DataBuffer::from_parts("idx", vec![10, 20, 30, 40], false);
+        assert_eq!(buffer[0], 10);
+        buffer[0] = 11;
+        buffer[1..3].copy_from_slice(&[21, 31]);
+        assert_eq!(buffer[1..3], [21, 31]);
+        assert_eq!(buffer[2..], [31, 40]);
+        assert_eq!(buffer.data, vec![11, 21, 31, 40]);
+    }
+    
+    #[test]
+    #[should_panic]
+    fn index_out_of_range_panics() {
+        let buffer = DataBuffer::from_parts("idx", vec![1, 2], false);
+        let _ = buffer[2];
+    }
+    
+    #[test]
+    fn collect_into_buffer() {
+        let range: DataBuffer = (0..10).collect();
+        assert_eq!(range.data, (0..10).collect::<Vec<_>>());
+        assert_eq!(range.name(), "collected_10");
+        
+        let squares: DataBuffer = (1..=4).map(|x| x * x).collect();
+        assert_eq!(squares.data, vec![1, 4, 9, 16]);
+        
+        let empty: DataBuffer = core::iter::empty().collect();
+        assert!(empty.is_empty());
+        assert_eq!(empty.name(), "collected_0");
+    }
+    
+    #[test]
+    fn default_starts_empty_and_grows() {
+        let mut buffer: DataBuffer = DataBuffer::default();
+        assert_eq!(buffer.len(), 0);
+        assert_eq!(buffer.capacity(), 0);
+        assert_eq!(buffer.name(), "default");
+        assert_eq!(buffer.heap_bytes(), 0);  // Neither elements nor name on the heap
+        assert!(buffer.verbose);  // So it still reports its drop
+        buffer.push(1);
+        buffer.extend([2, 3]);
+        assert_eq!(buffer.data, vec![1, 2, 3]);
+        
+        let named: DataBuffer = DataBuffer::new_empty("named");
+        assert_eq!((named.name(), named.len(), named.capacity()), ("named", 0, 0));
+    }
+    
+    #[cfg(feature = "std")]
+    #[test]
+    fn normalized_unit_vector() {
+        let buffer = DataBuffer::from_parts("v", vec![3, 4], false);
+        let unit = buffer.normalized().unwrap();
+        assert!((unit[0] - 0.6).abs() < 1e-12 && (unit[1] - 0.8).abs() < 1e-12);
+        
+        let zeros: DataBuffer = DataBuffer::new_quiet("zeros", 3);
+        match zeros.normalized() {
+            Err(BufferError::ZeroNorm) => {}
+            other => panic!("expected ZeroNorm, got {:?}", other),
+        }
+        assert_eq!(BufferError::ZeroNorm.to_string(), "cannot normalize a vector with zero norm");
+    }
+    
+    #[test]
+    fn diff_of_neighbours() {
+        let squares = DataBuffer::from_parts("sq", vec![1, 4, 9, 16], false);
+        let diff = squares.diff();
+        assert_eq!(diff.data, vec![3, 5, 7]);
+        assert_eq!(diff.name(), "sq_diff");
+        
+        let single = DataBuffer::from_parts("one", vec![42], false);
+        assert!(single.diff().is_empty());
+        let extremes = DataBuffer::from_parts("x", vec![i32::MIN, i32::MAX], false);
+        assert_eq!(extremes.diff().data, vec![-1]);  // Wraps instead of panicking
+    }
+    
+    #[test]
+    fn zip_with_cases() {
+        let a = DataBuffer::from_parts("a", vec![1, 5, -3], false);
+        let b = DataBuffer::from_parts("b", vec![4, 2, -1], false);
+        let max = a.zip_with(&b, core::cmp::max).unwrap();
+        assert_eq!(max.data, vec![4, 5, -1]);
+        assert_eq!(max.name(), "a_zip_b");
+        assert_eq!(a.zip_with(&b, |x, y| x * y).unwrap().data, vec![4, 10, 3]);
+        assert_eq!(a.data, vec![1, 5, -3]);  // Both inputs only borrowed
+        
+        let short = DataBuffer::from_parts("s", vec![1], false);
+        match a.zip_with(&short, |x, _| x) {
+            Err(BufferError::LengthMismatch { left: 3, right: 1 }) => {}
+            other => panic!("expected LengthMismatch, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn convolve_valid_mode() {
+        let buffer = DataBuffer::from_parts("sig", vec![1, 2, 3, 4, 5], false);
+        let smoothed = buffer.convolve(&[1, 1, 1]).unwrap();
+        assert_eq!(smoothed.data, vec![6, 9, 12]);
+        assert_eq!(smoothed.name(), "sig_convolved");
+        assert_eq!(buffer.convolve(&[1, 0, -1]).unwrap().data, vec![2, 2, 2]);  // Kernel is flipped
+        
+        let loud = DataBuffer::from_parts("loud", vec![i32::MAX, i32::MAX, i32::MIN], false);
+        assert_eq!(loud.convolve(&[1, 1]).unwrap().data, vec![i32::MAX, -1]);  // Saturates
+        
+        assert!(buffer.convolve(&[1; 6]).is_err());
+        match buffer.convolve(&[]) {
+            Err(BufferError::InvalidArgument(_)) => {}
+            other => panic!("expected InvalidArgument, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn deque_both_ends() {
+        let mut deque = DequeBuffer::new();
+        deque.push_back(2);
+        deque.push_front(1);
+        deque.push_back(3);
+        assert_eq!(deque.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(deque.pop_front(), Some(1));
+        assert_eq!(deque.pop_back(), Some(3));
+        assert_eq!(deque.len(), 1);
+        assert_eq!(deque.into_sum(), 2);
+        
+        let buffer = DataBuffer::from_parts("d", vec![4, 5, 6], false);
+        let mut converted = DequeBuffer::from(buffer);
+        assert_eq!(converted.pop_front(), Some(4));
+        assert_eq!(converted.pop_back(), Some(6));
+        assert_eq!(converted.pop_back(), Some(5));
+        assert!(converted.is_empty());
+        assert_eq!(converted.pop_front(), None);
+    }
+    
+    #[test]
+    fn literal_names_are_borrowed() {
+        let literal: DataBuffer = DataBuffer::new_quiet("static_name", 1);
+        assert!(matches!(literal.name, Cow::Borrowed("static_name")));
+        let default: DataBuffer = DataBuffer::default();
+        assert!(matches!(default.name, Cow::Borrowed("default")));
+        
+        let computed: DataBuffer = DataBuffer::new_quiet(format!("job_{}", 7), 1);
+        assert!(matches!(computed.name, Cow::Owned(ref name) if name == "job_7"));
+        assert_eq!(literal.heap_bytes() + "job_7".len(), computed.heap_bytes());
+    }
+    
+    #[test]
+    fn merge_sorted_cases() {
+        let odd = DataBuffer::from_parts("odd", vec![1, 3, 5], false);
+        let even = DataBuffer::from_parts("even", vec![2, 4, 6], false);
+        let merged = odd.merge_sorted(even);
+        assert_eq!(merged.data, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(merged.name(), "odd_merged_even");
+        
+        let empty: DataBuffer = DataBuffer::from_parts("empty", Vec::new(), false);
+        let merged = empty.merge_sorted(merged);
+        assert_eq!(merged.data, vec![1, 2, 3, 4, 5, 6]);
+        let empty: DataBuffer = DataBuffer::from_parts("empty", Vec::new(), false);
+        assert_eq!(merged.merge_sorted(empty).len(), 6);
+        
+        let dups = DataBuffer::from_parts("a", vec![1, 2, 2], false)
+            .merge_sorted(DataBuffer::from_parts("b", vec![2, 3], false));
+        assert_eq!(dups.data, vec![1, 2, 2, 2, 3]);
+    }
+    
+    #[test]
+    fn partition_cases() {
+        let buffer = DataBuffer::from_parts("n", vec![1, 2, 3, 4], false);
+        let (even, odd) = buffer.partition(|&x| x % 2 == 0);
+        assert_eq!((even.name(), odd.name()), ("n_yes", "n_no"));
+        assert_eq!(even.data, vec![2, 4]);
+        assert_eq!(odd.data, vec![1, 3]);
+        
+        let (all, none) = even.partition(|_| true);
+        assert_eq!(all.data, vec![2, 4]);
+        assert!(none.is_empty());
+        let (none, all) = odd.partition(|_| false);
+        assert!(none.is_empty());
+        assert_eq!(all.data, vec![1, 3]);
+    }
+    
+    #[test]
+    fn find_peaks_cases() {
+        let buffer = DataBuffer::from_parts("p", vec![1, 3, 2, 5, 4], false);
+        assert_eq!(buffer.find_peaks(), vec![1, 3]);
+        
+        let rising = DataBuffer::from_parts("up", vec![1, 2, 3, 4], false);
+        assert!(rising.find_peaks().is_empty());
+        let falling = DataBuffer::from_parts("down", vec![4, 3, 2, 1], false);
+        assert!(falling.find_peaks().is_empty());
+        
+        let plateau = DataBuffer::from_parts("flat", vec![1, 3, 3, 1], false);
+        assert!(plateau.find_peaks().is_empty());
+        let short: DataBuffer = DataBuffer::from_parts("short", vec![5, 1], false);
+        assert!(short.find_peaks().is_empty());
+    }
+    
+    #[test]
+    fn window_max_cases() {
+        let buffer = DataBuffer::from_parts("w", vec![1, 3, -1, -3, 5, 3, 6, 7], false);
+        assert_eq!(buffer.window_max(3).unwrap(), vec![3, 3, 5, 5, 6, 7]);
+        assert_eq!(buffer.window_max(1).unwrap(), buffer.data);
+        assert_eq!(buffer.window_max(8).unwrap(), vec![7]);
+        
+        for k in [0, 9] {
+            match buffer.window_max(k) {
+                Err(BufferError::InvalidArgument(_)) => {}
+                other => panic!("expected InvalidArgument for k = {}, got {:?}", k, other),
+            }
+        }
+    }
+    
+    #[test]
+    fn apply_gain_saturates_and_counts() {
+        let mut buffer = DataBuffer::from_parts("g", vec![1, i32::MAX - 1, i32::MIN + 5, -3, i32::MAX / 2], false);
+        assert_eq!(buffer.apply_gain(2), 2);
+        assert_eq!(buffer.data, vec![2, i32::MAX, i32::MIN, -6, i32::MAX - 1]);
+        
+        assert_eq!(buffer.apply_gain(-1), 1);  // -i32::MIN overflows
+        assert_eq!(buffer.data, vec![-2, -i32::MAX, i32::MAX, 6, -(i32::MAX - 1)]);
+        assert_eq!(buffer.apply_gain(1), 0);
+    }
+    
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_round_trip_keeps_name_and_data() {
+        let buffer: DataBuffer = DataBuffer::from_parts("json", vec![1, -2, 3], false);
+        let json = buffer.to_json().expect("a buffer serializes");
+        
+        let mut decoded: DataBuffer = DataBuffer::from_json(&json).expect("its own JSON parses");
+        decoded.verbose = false;
+        assert_eq!(decoded.name(), buffer.name());
+        assert_eq!(decoded.data, buffer.data);
+    }
+    
+    #[test]
+    fn slice_methods_work_through_deref() {
+        let buffer: DataBuffer = DataBuffer::from_parts("squares", vec![1, 4, 9, 16], false);
+        let pairs: Vec<(i32, i32)> = buffer.windows(2).map(|w| (w[0], w[1])).collect();
+        assert_eq!(pairs, [(1, 4), (4, 9), (9, 16)]);
+        assert_eq!(buffer.first(), Some(&1));
+        assert_eq!(buffer.last(), Some(&16));
+        
+        fn total(values: &[i32]) -> i32 {
+            values.iter().sum()
+        }
+        assert_eq!(total(&buffer), 30);
+    }
+    
+    #[test]
+    fn pool_reuses_the_released_allocation() {
+        let mut pool: BufferPool = BufferPool::new();
+        let released: DataBuffer = DataBuffer::from_parts("released", vec![7; 8], false);
+        let ptr = released.as_ptr();
+        let capacity = released.capacity();
+        pool.release(released);
+        
+        let reused = pool.acquire("reused", 6).unwrap();
+        assert_eq!(reused.as_ptr(), ptr);
+        assert_eq!(reused.capacity(), capacity);
+        assert_eq!(reused.len(), 6);
+    }
+    
+    #[cfg(feature = "std")]
+    #[test]
+    fn channel_consumer_sums_what_was_sent() {
+        use std::sync::mpsc;
+        
+        let (sender, receiver) = mpsc::channel::<DataBuffer<i32>>();
+        let producer = thread::spawn(move || {
+            let mut sent = 0;
+            for id in 1..=5 {
+                let mut buffer = DataBuffer::new_quiet(format!("job{}", id), id * 2);
+                buffer.fill_with_values(id as i32);
+                sent += buffer.iter().sum::<i32>();
+                sender.send(buffer).unwrap();
+            }
+            sent
+        });
+        let consumer = thread::spawn(move || receiver.into_iter().map(DataBuffer::into_sum).sum::<i32>());
+        
+        let sent = producer.join().unwrap();
+        assert_eq!(consumer.join().unwrap(), sent);
+        assert!(sent > 0);
+    }
+    
+    #[test]
+    fn mem_ops_move_whole_vectors() {
+        let mut left: DataBuffer = DataBuffer::from_parts("left", vec![1, 1, 1], false);
+        let mut right: DataBuffer = DataBuffer::from_parts("right", vec![2, 2], false);
+        
+        core::mem::swap(&mut left.data, &mut right.data);
+        assert_eq!(left.data, [2, 2]);
+        assert_eq!(right.data, [1, 1, 1]);
+        
+        let old = core::mem::replace(&mut left.data, vec![9, 9, 9, 9]);
+        assert_eq!(old, [2, 2]);
+        assert_eq!(left.data, [9, 9, 9, 9]);
+        
+        let taken = core::mem::take(&mut right.data);
+        assert!(right.data.is_empty());
+        assert_eq!(right.data.capacity(), 0);
+        assert_eq!(taken, [1, 1, 1]);
+    }
+    
+    #[test]
+    fn boxed_slice_drops_spare_capacity() {
+        let mut buffer: DataBuffer = DataBuffer::from_parts("boxed", Vec::with_capacity(16), false);
+        buffer.extend_from_slice(&[5, 6, 7]);
+        assert!(buffer.capacity() > buffer.len());
+        
+        let boxed = buffer.into_boxed_slice();
+        assert_eq!(boxed.len(), 3);
+        assert_eq!(&*boxed, &[5, 6, 7]);
+    }
+    
+    #[test]
+    fn resize_reports_reallocation() {
+        let mut buffer: DataBuffer = DataBuffer::from_parts("grow", Vec::with_capacity(8), false);
+        buffer.extend_from_slice(&[1, 2]);
+        let ptr = buffer.as_ptr();
+        assert!(!buffer.resize(8, 0));  // Fits in the reserved capacity
+        assert_eq!(buffer.as_ptr(), ptr);
+        assert_eq!(buffer.capacity(), 8);
+        
+        assert!(buffer.resize(9, 0));
+        assert!(buffer.capacity() >= 9);
+        assert!(!buffer.resize(1, 0));  // Truncating never reallocates
+    }
+    
+    #[test]
+    fn errors_inside_a_demo_reach_the_caller() {
+        fn split_past_end() -> Result<(), BufferError> {
+            let buffer: DataBuffer = DataBuffer::from_parts("short", vec![1, 2, 3], false);
+            let (mut left, mut right) = buffer.split_at(4)?;
+            left.verbose = false;
+            right.verbose = false;
+            panic!("split_at(4) of 3 elements succeeded");
+        }
+        
+        fn truncated_load() -> Result<(), BufferError> {
+            let mut loaded = DataBuffer::from_bytes_le(&[3, 0])?;
+            loaded.verbose = false;
+            panic!("a 2-byte input decoded to {:?}", loaded.data);
+        }
+        
+        match split_past_end() {
+            Err(BufferError::OutOfBounds { index: 4, len: 3 }) => {}
+            other => panic!("expected OutOfBounds, got {:?}", other),
+        }
+        match truncated_load() {
+            Err(BufferError::Truncated { actual: 2, .. }) => {}
+            other => panic!("expected Truncated, got {:?}", other),
+        }
+    }
+    
+    #[cfg(feature = "std")]
+    #[test]
+    fn move_keeps_age_and_generation() {
+        let original: DataBuffer = DataBuffer::from_parts("aged", vec![1, 2], false);
+        let copy = original.clone();
+        let (created_at, generation) = (copy.created_at, copy.generation);
+        assert_eq!(generation, 1);
+        
+        fn take_ownership(buffer: DataBuffer) -> DataBuffer {
+            buffer
+        }
+        let moved = take_ownership(copy);
+        assert_eq!(moved.created_at, created_at);
+        assert_eq!(moved.generation, generation);
+        assert_eq!(moved.clone().generation, moved.generation + 1);
+    }
+}
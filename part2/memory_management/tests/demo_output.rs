@@ -0,0 +1,95 @@
+//! Runs the built `rust_memory` binary and checks what the demos print
+//! The lifecycle messages are only on stdout without the "log" feature, so
+//! from this directory build and run exactly:
+//! `rustc --crate-type=lib --cfg 'feature="std"' memory_buffers.rs`
+//! `rustc rust_memory.rs --extern memory_buffers=libmemory_buffers.rlib`
+//! `rustc --test tests/demo_output.rs && ./demo_output`
+//! RUST_MEMORY_BIN overrides the binary path (default `./rust_memory`)
+
+use std::env;
+use std::process::Command;
+
+fn binary() -> String {
+    env::var("RUST_MEMORY_BIN").unwrap_or_else(|_| String::from("./rust_memory"))
+}
+
+/// Stdout of `rust_memory <arg>`, after checking that it exited successfully
+fn run_demo(arg: &str) -> String {
+    let bin = binary();
+    let output = Command::new(&bin)
+        .arg(arg)
+        .output()
+        .unwrap_or_else(|e| panic!("cannot run {}: {}", bin, e));
+    assert!(output.status.success(), "demo {} failed: {:?}", arg, output);
+    String::from_utf8(output.stdout).expect("demo output is UTF-8")
+}
+
+/// Like `run_demo`, but fails clearly when the lifecycle messages went to a
+/// logger instead of stdout because the binary was built with "log"
+fn run_verbose_demo(arg: &str) -> String {
+    let output = run_demo(arg);
+    assert!(
+        output.contains("Creating buffer"),
+        "no lifecycle messages on stdout; rebuild rust_memory without the \"log\" feature:\n{}",
+        output
+    );
+    output
+}
+
+/// Index of the first line containing `needle`, failing with the full output
+fn line_of(output: &str, needle: &str) -> usize {
+    output
+        .lines()
+        .position(|line| line.contains(needle))
+        .unwrap_or_else(|| panic!("{:?} not found in:\n{}", needle, output))
+}
+
+#[test]
+fn demo_1_reports_the_move() {
+    let output = run_verbose_demo("1");
+    let created = line_of(&output, "Creating buffer 'Buffer1'");
+    let moved = line_of(&output, "buffer1 is no longer accessible");
+    let dropped = line_of(&output, "Dropping buffer 'Buffer1'");
+    // The buffer is dropped once, by its new owner, after the move
+    assert!(created < moved && moved < dropped);
+    assert_eq!(output.matches("Dropping buffer 'Buffer1'").count(), 1);
+}
+
+#[test]
+fn demo_4_prints_the_sum() {
+    let output = run_verbose_demo("4");
+    let consumed = line_of(&output, "Buffer 'Buffer4' consumed, sum = 21");
+    let dropped = line_of(&output, "Dropping buffer 'Buffer4'");
+    let reported = line_of(&output, "Final sum: 21");
+    // into_sum drops the buffer before it returns the sum to the caller
+    assert!(consumed < dropped && dropped < reported);
+}
+
+#[test]
+fn drop_order_demo_is_reverse_declaration_order() {
+    let output = run_verbose_demo("36");
+    let order: Vec<usize> = ["'Inner'", "'C'", "'B'", "'A'"]
+        .iter()
+        .map(|name| line_of(&output, &format!("Dropping buffer {}", name)))
+        .collect();
+    assert!(order.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", order);
+}
+
+#[test]
+fn quiet_buffer_prints_no_lifecycle_messages() {
+    // Demo 1 shows the same messages do reach stdout for a verbose buffer
+    run_verbose_demo("1");
+    let output = run_demo("38");
+    let start = line_of(&output, "--- DEMO 38");
+    let section = output.lines().skip(start).collect::<Vec<_>>().join("\n");
+    for message in ["Creating buffer", "Filled buffer", "consumed, sum", "Dropping buffer"].iter() {
+        assert!(!section.contains(message), "{:?} printed for a quiet buffer:\n{}", message, section);
+    }
+    assert!(section.contains("Sum computed silently: 15"));
+}
+
+#[test]
+fn unknown_demo_fails() {
+    let status = Command::new(binary()).arg("0").output().expect("binary runs").status;
+    assert!(!status.success());
+}
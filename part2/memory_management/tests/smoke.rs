@@ -0,0 +1,27 @@
+//! Uses the library only through its public API, as another crate would
+//! Build with `rustc --test tests/smoke.rs --extern memory_buffers=libmemory_buffers.rlib`
+
+extern crate memory_buffers;
+
+use memory_buffers::{BufferError, DataBuffer, DataBufferBuilder};
+
+#[test]
+fn data_buffer_from_outside_the_crate() {
+    let mut buffer: DataBuffer = DataBuffer::new_quiet("smoke", 4);
+    buffer.fill_with_values(1);
+    assert_eq!(buffer.name(), "smoke");
+    assert_eq!(buffer.len(), 4);
+    assert_eq!(&buffer[..], &[1, 2, 3, 4]);
+    assert_eq!(buffer.get(3).unwrap(), 4);
+    assert_eq!(buffer.into_sum(), 10);
+}
+
+#[test]
+fn errors_are_public() {
+    match DataBuffer::<i32>::try_new("empty", 0) {
+        Err(BufferError::EmptyBuffer) => {}
+        other => panic!("expected EmptyBuffer, got {:?}", other),
+    }
+    let built = DataBufferBuilder::new().name("built").size(2).fill_start(5).build().unwrap();
+    assert_eq!(Vec::from(built), vec![5, 6]);
+}